@@ -0,0 +1,93 @@
+//! Runtime API definition for the INV4 pallet.
+//!
+//! Runtimes implement it by forwarding to the pallet's queries, see [`pallet_inv4::queries`]:
+//!
+//! ```ignore
+//! impl pallet_inv4_runtime_api::Inv4Api<Block, CoreId, AccountId, Hash, Balance, BlockNumber, RuntimeCall, RuntimeEvent, RelayBalance>
+//!     for Runtime
+//! {
+//!     fn core_info(core_id: CoreId) -> Option<CoreInfo<AccountId, CoreMetadata<Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, Hash>, Balance>> {
+//!         INV4::core_info(core_id)
+//!     }
+//!
+//!     fn cores_of(account: AccountId) -> Vec<CoreId> {
+//!         INV4::cores_of(account)
+//!     }
+//!
+//!     fn members_of(core_id: CoreId) -> Vec<AccountId> {
+//!         INV4::members_of(core_id)
+//!     }
+//!
+//!     fn pending_proposals(core_id: CoreId) -> Vec<PendingProposal<AccountId, Hash, Balance, RuntimeCall>> {
+//!         INV4::pending_proposals(core_id)
+//!     }
+//!
+//!     fn vote_would_execute(core_id: CoreId, call_hash: Hash, voter: AccountId, aye: bool) -> bool {
+//!         INV4::vote_would_execute(core_id, call_hash, voter, aye)
+//!     }
+//!
+//!     fn dry_run_call(
+//!         core_id: CoreId,
+//!         call: RuntimeCall,
+//!         fee_asset: FeeAsset,
+//!     ) -> Result<DryRunResult<RuntimeEvent, FeeAssetBalance<Balance, RelayBalance>>, DispatchError> {
+//!         INV4::dry_run_call(core_id, call, fee_asset)
+//!     }
+//!
+//!     fn archived_proposals(core_id: CoreId) -> Vec<ArchivedProposal<AccountId, Hash, Balance, BlockNumber>> {
+//!         INV4::archived_proposals(core_id)
+//!     }
+//! }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_inv4::{
+    archive::ArchivedProposal,
+    dry_run::DryRunResult,
+    fee_handling::{FeeAsset, FeeAssetBalance},
+    queries::PendingProposal,
+};
+pub use primitives::{CoreInfo, CoreMetadata};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait Inv4Api<CoreId, AccountId, Hash, Balance, BlockNumber, Call, Event, RelayBalance>
+    where
+        CoreId: Codec,
+        AccountId: Codec,
+        Hash: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Call: Codec,
+        Event: Codec,
+        RelayBalance: Codec,
+    {
+        /// Returns the details of a core.
+        fn core_info(core_id: CoreId) -> Option<CoreInfo<AccountId, CoreMetadata<Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, Hash>, Balance>>;
+
+        /// Returns the cores an account is a member of.
+        fn cores_of(account: AccountId) -> Vec<CoreId>;
+
+        /// Returns the members of a core.
+        fn members_of(core_id: CoreId) -> Vec<AccountId>;
+
+        /// Returns the proposals of a core in the voting stage, with their calls decoded.
+        fn pending_proposals(core_id: CoreId) -> Vec<PendingProposal<AccountId, Hash, Balance, Call>>;
+
+        /// Checks if a member's vote would execute a pending proposal right away.
+        fn vote_would_execute(core_id: CoreId, call_hash: Hash, voter: AccountId, aye: bool) -> bool;
+
+        /// Dispatches a call as the core and reports what it would do, without changing any state.
+        fn dry_run_call(
+            core_id: CoreId,
+            call: Call,
+            fee_asset: FeeAsset,
+        ) -> Result<DryRunResult<Event, FeeAssetBalance<Balance, RelayBalance>>, DispatchError>;
+
+        /// Returns the archived proposals of a core, from oldest to newest.
+        fn archived_proposals(core_id: CoreId) -> Vec<ArchivedProposal<AccountId, Hash, Balance, BlockNumber>>;
+    }
+}
@@ -9,18 +9,124 @@
 //! ### The module contains:
 //! - `CoreAccountDerivation` trait: The interface for our derivation method.
 //! - Pallet implementation: The specific logic used to derive AccountIds.
+//! - `DescribeCore` and `CoreDescription`: The description of core locations, built on xcm-builder's
+//!   `DescribeFamily`.
+//! - `core_account_hash` and `core_derivative_account_hash`: The derivation itself, shared with the XCM converters
+//!   in [`crate::xcm_conversion`].
+//!
+//! Cores can also control derivative sub-accounts, identified by an index within the core, see [`crate::derivatives`].
+//! They're derived the same way from the core location followed by a `GeneralIndex` junction.
 
 use crate::{Config, Pallet};
 use codec::{Compact, Encode};
 use frame_support::traits::Get;
 use sp_io::hashing::blake2_256;
-use xcm::v3::{BodyId, BodyPart, Junction, Junctions};
+use sp_std::vec::Vec;
+use xcm::v3::{BodyId, BodyPart, Junction, Junctions, MultiLocation};
+use xcm_builder::{DescribeFamily, DescribeLocation};
+
+/// Describes the location of a core, or of one of its derivative sub-accounts, relative to its chain.
+///
+/// Meant to be used as the interior describer of xcm-builder's [`DescribeFamily`], so core locations are described
+/// like any other location of a sibling chain, see [`CoreDescription`].
+pub struct DescribeCore;
+
+impl DescribeLocation for DescribeCore {
+    fn describe_location(location: &MultiLocation) -> Option<Vec<u8>> {
+        match location {
+            MultiLocation {
+                parents: 0,
+                interior: Junctions::X1(body),
+            } => Some(
+                (
+                    b"Body",
+                    BodyId::Index(core_body_index(body)?),
+                    BodyPart::Voice,
+                )
+                    .encode(),
+            ),
+
+            MultiLocation {
+                parents: 0,
+                interior: Junctions::X2(body, Junction::GeneralIndex(index)),
+            } => Some(
+                (
+                    b"Body",
+                    BodyId::Index(core_body_index(body)?),
+                    BodyPart::Voice,
+                    b"GeneralIndex",
+                    Compact::<u128>::from(*index),
+                )
+                    .encode(),
+            ),
+
+            _ => None,
+        }
+    }
+}
+
+/// Description of core locations, used with xcm-builder's [`xcm_builder::HashedDescription`] to derive their accounts.
+pub type CoreDescription = DescribeFamily<DescribeCore>;
+
+/// Account of the core `core_id` of the parachain `para_id`, see [`CoreAccountDerivation::derive_core_account`].
+pub fn core_account_hash(para_id: u32, core_id: u32) -> [u8; 32] {
+    sibling_location_hash(Junctions::X2(
+        Junction::Parachain(para_id),
+        core_body(core_id),
+    ))
+}
+
+/// Account of a derivative sub-account of the core `core_id` of the parachain `para_id`,
+/// see [`CoreAccountDerivation::derive_core_derivative_account`].
+pub fn core_derivative_account_hash(para_id: u32, core_id: u32, index: u128) -> [u8; 32] {
+    sibling_location_hash(Junctions::X3(
+        Junction::Parachain(para_id),
+        core_body(core_id),
+        Junction::GeneralIndex(index),
+    ))
+}
+
+/// Hashes the [`CoreDescription`] of a core location from the perspective of a sibling chain, the same way
+/// [`xcm_builder::HashedDescription`] does.
+fn sibling_location_hash(interior: Junctions) -> [u8; 32] {
+    let description = CoreDescription::describe_location(&MultiLocation {
+        parents: 1,
+        interior,
+    })
+    .expect("Core locations always have a description; qed");
+
+    blake2_256(&description)
+}
+
+/// The `Plurality` junction describing the core `core_id` within its chain.
+fn core_body(core_id: u32) -> Junction {
+    Junction::Plurality {
+        id: BodyId::Index(core_id),
+        part: BodyPart::Voice,
+    }
+}
+
+/// Returns the core id of a core's `Plurality` junction.
+pub(crate) fn core_body_index(junction: &Junction) -> Option<u32> {
+    match junction {
+        Junction::Plurality {
+            id: BodyId::Index(core_index),
+            part: BodyPart::Voice,
+        } => Some(*core_index),
+        _ => None,
+    }
+}
+
 /// Trait providing the XCM location and the derived account of a core.
 pub trait CoreAccountDerivation<T: Config> {
     /// Derives the core's AccountId.
     fn derive_core_account(core_id: T::CoreId) -> T::AccountId;
     /// Specifies a core's location.
     fn core_location(core_id: T::CoreId) -> Junctions;
+    /// Derives the AccountId of one of the core's derivative sub-accounts.
+    fn derive_core_derivative_account(core_id: T::CoreId, index: u16) -> T::AccountId;
+    /// Specifies the location of one of the core's derivative sub-accounts.
+    fn core_derivative_location(core_id: T::CoreId, index: u16) -> Junctions;
 }
 
 impl<T: Config> CoreAccountDerivation<T> for Pallet<T>
@@ -31,24 +137,27 @@ where
     /// This derivation allows the local account address to match the account address in other parachains.
     /// Reference: https://github.com/paritytech/polkadot-sdk/blob/master/polkadot/xcm/xcm-builder/src/location_conversion.rs
     fn derive_core_account(core_id: T::CoreId) -> T::AccountId {
-        blake2_256(
-            &(
-                b"SiblingChain",
-                Compact::<u32>::from(T::ParaId::get()),
-                (b"Body", BodyId::Index(core_id.into()), BodyPart::Voice).encode(),
-            )
-                .encode(),
-        )
-        .into()
+        core_account_hash(T::ParaId::get(), core_id.into()).into()
     }
     /// Core location is defined as a plurality within the parachain.
     fn core_location(core_id: T::CoreId) -> Junctions {
         Junctions::X2(
             Junction::Parachain(T::ParaId::get()),
-            Junction::Plurality {
-                id: BodyId::Index(core_id.into()),
-                part: BodyPart::Voice,
-            },
+            core_body(core_id.into()),
+        )
+    }
+
+    /// HashedDescription of the derivative location from the perspective of a sibling chain.
+    /// The interior is described by [`DescribeCore`] as the core's body followed by the `GeneralIndex` of the sub-account.
+    fn derive_core_derivative_account(core_id: T::CoreId, index: u16) -> T::AccountId {
+        core_derivative_account_hash(T::ParaId::get(), core_id.into(), index.into()).into()
+    }
+    /// Derivative location is defined as a general index within the core's plurality.
+    fn core_derivative_location(core_id: T::CoreId, index: u16) -> Junctions {
+        Junctions::X3(
+            Junction::Parachain(T::ParaId::get()),
+            core_body(core_id.into()),
+            Junction::GeneralIndex(index.into()),
         )
     }
 }
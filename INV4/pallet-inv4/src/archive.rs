@@ -0,0 +1,116 @@
+//! Proposal Archive.
+//!
+//! ## Overview
+//!
+//! Keeps a bounded history of the resolved proposals of each core, so their outcome can be queried
+//! from storage without an archive node.
+//!
+//! Every core has a ring buffer of `MaxArchivedProposals` slots in [`ProposalArchive`],
+//! once it's full the oldest resolved proposal is overwritten by the newest one.
+//!
+//! ### Core functionalities:
+//! - `archive_proposal`: Records a proposal that was executed or canceled.
+//! - `archived_proposals`: Returns the archived proposals of a core, from oldest to newest.
+
+use super::pallet::*;
+use crate::voting::Tally;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// How a proposal left the voting stage.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub enum ProposalOutcome {
+    /// The proposal passed and its calls were dispatched.
+    Executed,
+    /// The proposal was canceled by the core.
+    Canceled,
+    /// The proposal was canceled because one of its dependencies failed.
+    DependencyFailed,
+}
+
+/// A resolved proposal kept in the archive.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct ArchivedProposal<AccountId, Hash, Balance, BlockNumber> {
+    pub call_hash: Hash,
+    pub proposer: AccountId,
+    /// Final tally of the proposal.
+    pub ayes: Balance,
+    pub nays: Balance,
+    pub voters: u32,
+    pub outcome: ProposalOutcome,
+    /// Result of dispatching the calls, only set for executed proposals.
+    pub result: Option<DispatchResult>,
+    /// Block the proposal was resolved at.
+    pub resolved_at: BlockNumber,
+}
+
+pub type ArchivedProposalOf<T> = ArchivedProposal<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::Hash,
+    BalanceOf<T>,
+    BlockNumberFor<T>,
+>;
+
+impl<T: Config> Pallet<T> {
+    /// Records a resolved proposal in the core's archive, overwriting the oldest one if the archive is full.
+    pub(crate) fn archive_proposal(
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        proposer: T::AccountId,
+        tally: &Tally<T>,
+        outcome: ProposalOutcome,
+        result: Option<DispatchResult>,
+    ) {
+        let max_archived = T::MaxArchivedProposals::get();
+
+        // Archive disabled.
+        if max_archived == 0 {
+            return;
+        }
+
+        let archived = ProposalArchiveCount::<T>::mutate(core_id, |count| {
+            let archived = *count;
+            *count = count.saturating_add(1);
+            archived
+        });
+
+        ProposalArchive::<T>::insert(
+            core_id,
+            (archived % max_archived as u64) as u32,
+            ArchivedProposal {
+                call_hash,
+                proposer,
+                ayes: tally.ayes,
+                nays: tally.nays,
+                voters: tally.voters,
+                outcome,
+                result,
+                resolved_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+    }
+
+    /// Returns the archived proposals of a core, from oldest to newest.
+    pub fn archived_proposals(core_id: T::CoreId) -> Vec<ArchivedProposalOf<T>> {
+        let max_archived = T::MaxArchivedProposals::get();
+        let archived = ProposalArchiveCount::<T>::get(core_id);
+
+        if max_archived == 0 {
+            return Vec::new();
+        }
+
+        // Once the ring buffer wrapped around, the oldest proposal is in the next slot to be written.
+        let (first, len) = if archived > max_archived as u64 {
+            ((archived % max_archived as u64) as u32, max_archived)
+        } else {
+            (0, archived as u32)
+        };
+
+        (0..len)
+            .filter_map(|i| ProposalArchive::<T>::get(core_id, (first + i) % max_archived))
+            .collect()
+    }
+}
@@ -0,0 +1,117 @@
+//! CoreAssets Hooks.
+//!
+//! ## Overview
+//!
+//! This module provides ready-made `orml_tokens` currency hooks for the token pallet holding the cores' voting tokens.
+//! Runtimes should set [`INV4TokenHooks`] as the `CurrencyHooks` of that pallet so that:
+//!
+//! - Transfers of a core's voting token are rejected while the core has `frozen_tokens` set, and restricted to members
+//!   and whitelisted accounts while the core has `members_only_transfers` set.
+//! - Voting tokens locked by vesting schedules can't be transferred.
+//! - `CoreMembers`, `MemberCores` and `CoreMemberCount` are kept in sync with the accounts holding a non-zero balance of each core's voting token.
+//!
+//! Members are added when a token account is created or receives a deposit and removed when the token account is killed
+//! or slashed down to a zero balance.
+
+use crate::{BalanceOf, Config, CoreMemberCount, CoreMembers, LastProposal, MemberCores, Pallet};
+use core::marker::PhantomData;
+use frame_support::traits::fungibles::Inspect;
+use orml_traits2::{
+    currency::{MutationHooks, OnDeposit, OnSlash, OnTransfer},
+    Happened,
+};
+use sp_runtime::{traits::Zero, DispatchResult};
+
+impl<T: Config> Pallet<T> {
+    /// Registers an account as a member of a core.
+    pub fn add_member(core_id: &T::CoreId, member: &T::AccountId) {
+        if !CoreMembers::<T>::contains_key(core_id, member) {
+            CoreMembers::<T>::insert(core_id, member, ());
+            MemberCores::<T>::insert(member, core_id, ());
+            CoreMemberCount::<T>::mutate(core_id, |count| *count = count.saturating_add(1));
+        }
+    }
+
+    /// Removes an account from the members of a core.
+    pub fn remove_member(core_id: &T::CoreId, member: &T::AccountId) {
+        if CoreMembers::<T>::contains_key(core_id, member) {
+            CoreMembers::<T>::remove(core_id, member);
+            MemberCores::<T>::remove(member, core_id);
+            CoreMemberCount::<T>::mutate(core_id, |count| *count = count.saturating_sub(1));
+            LastProposal::<T>::remove(core_id, member);
+        }
+    }
+}
+
+/// Rejects transfers of a core's voting token not allowed by the core's transfer restrictions or touching vesting tokens.
+pub struct EnforceTransferRestrictions<T>(PhantomData<T>);
+
+impl<T: Config> OnTransfer<T::AccountId, T::CoreId, BalanceOf<T>>
+    for EnforceTransferRestrictions<T>
+{
+    fn on_transfer(
+        core_id: T::CoreId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        Pallet::<T>::ensure_transfer_allowed(core_id, to)?;
+        Pallet::<T>::ensure_unlocked(core_id, from, amount)
+    }
+}
+
+/// Makes sure the receiver of a deposit is registered as a member of the core.
+pub struct AddMemberOnDeposit<T>(PhantomData<T>);
+
+impl<T: Config> OnDeposit<T::AccountId, T::CoreId, BalanceOf<T>> for AddMemberOnDeposit<T> {
+    fn on_deposit(core_id: T::CoreId, who: &T::AccountId, _amount: BalanceOf<T>) -> DispatchResult {
+        if !T::AssetsProvider::balance(core_id, who).is_zero() {
+            Pallet::<T>::add_member(&core_id, who);
+        }
+
+        Ok(())
+    }
+}
+
+/// Makes sure an account slashed down to a zero balance is removed from the members of the core.
+pub struct RemoveMemberOnSlash<T>(PhantomData<T>);
+
+impl<T: Config> OnSlash<T::AccountId, T::CoreId, BalanceOf<T>> for RemoveMemberOnSlash<T> {
+    fn on_slash(core_id: T::CoreId, who: &T::AccountId, _amount: BalanceOf<T>) {
+        if T::AssetsProvider::total_balance(core_id, who).is_zero() {
+            Pallet::<T>::remove_member(&core_id, who);
+        }
+    }
+}
+
+/// Adds the owner of a new token account to the members of the core.
+pub struct HandleNewMembers<T>(PhantomData<T>);
+
+impl<T: Config> Happened<(T::AccountId, T::CoreId)> for HandleNewMembers<T> {
+    fn happened((member, core_id): &(T::AccountId, T::CoreId)) {
+        Pallet::<T>::add_member(core_id, member)
+    }
+}
+
+/// Removes the owner of a killed token account from the members of the core.
+pub struct HandleRemovedMembers<T>(PhantomData<T>);
+
+impl<T: Config> Happened<(T::AccountId, T::CoreId)> for HandleRemovedMembers<T> {
+    fn happened((member, core_id): &(T::AccountId, T::CoreId)) {
+        Pallet::<T>::remove_member(core_id, member)
+    }
+}
+
+/// Currency hooks to be used by the token pallet holding the cores' voting tokens.
+pub struct INV4TokenHooks<T>(PhantomData<T>);
+
+impl<T: Config> MutationHooks<T::AccountId, T::CoreId, BalanceOf<T>> for INV4TokenHooks<T> {
+    type PreTransfer = EnforceTransferRestrictions<T>;
+    type OnDust = ();
+    type OnSlash = RemoveMemberOnSlash<T>;
+    type PreDeposit = ();
+    type PostDeposit = AddMemberOnDeposit<T>;
+    type PostTransfer = ();
+    type OnNewTokenAccount = HandleNewMembers<T>;
+    type OnKilledTokenAccount = HandleRemovedMembers<T>;
+}
@@ -2,32 +2,44 @@
 
 use super::*;
 use crate::{
-    fee_handling::FeeAsset,
+    fee_handling::{FeeAsset, FeeAssetBalance},
+    handles::CoreHandleOf,
     multisig::MAX_SIZE,
     origin::{INV4Origin, MultisigInternalOrigin},
+    proposal_limits::ProposalLimits,
+    vesting::VestingSchedule,
     voting::{Tally, Vote},
     BalanceOf,
 };
 use core::convert::TryFrom;
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::{
     dispatch::PostDispatchInfo,
     pallet_prelude::DispatchResultWithPostInfo,
-    traits::{Currency, Get},
-    BoundedBTreeMap, BoundedVec,
+    traits::{Currency, EnsureOrigin, Get},
+    weights::Weight,
+    BoundedVec,
 };
 use frame_system::RawOrigin as SystemOrigin;
+use primitives::{CoreMetadata, MinimumVoters};
 use sp_runtime::{
     traits::{Bounded, Hash, Zero},
     DispatchError, DispatchErrorWithPostInfo, Perbill,
 };
-use sp_std::{
-    collections::btree_map::BTreeMap, convert::TryInto, iter::Sum, ops::Div, prelude::*, vec,
+use sp_std::{convert::TryInto, iter::Sum, ops::Div, prelude::*, vec};
+use xcm::{
+    v3::{
+        Junction::{AccountId32, Parachain},
+        Junctions::X2,
+        MultiLocation,
+    },
+    VersionedMultiLocation,
 };
 
 use crate::Pallet as INV4;
 
 const SEED: u32 = 0;
+const MAX_VOTERS: u32 = 1000;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
@@ -60,13 +72,22 @@ where
 
     INV4::<T>::create_core(
         SystemOrigin::Signed(whitelisted_caller()).into(),
-        vec![].try_into().unwrap(),
+        Default::default(),
         perbill_one(),
         perbill_one(),
         FeeAsset::Native,
     )
 }
 
+fn mock_metadata<T: Config>(description_length: u32) -> inv4_core::CoreMetadataOf<T> {
+    CoreMetadata {
+        description: vec![u8::MAX; description_length as usize]
+            .try_into()
+            .unwrap(),
+        ..Default::default()
+    }
+}
+
 fn mock_mint<T: Config>() -> Result<(), DispatchError>
 where
     Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
@@ -99,6 +120,42 @@ where
     )
 }
 
+fn mock_invite<T: Config>() -> Result<(), DispatchError>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance:
+        Sum,
+    <T as frame_system::Config>::RuntimeOrigin: From<INV4Origin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    INV4::<T>::invite_member(
+        INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())).into(),
+        BalanceOf::<T>::max_value().div(2u32.into()),
+        account("target", 0, SEED),
+    )
+}
+
+fn mock_token_sale<T: Config>() -> Result<(), DispatchError>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance:
+        Sum,
+    <T as frame_system::Config>::RuntimeOrigin: From<INV4Origin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    let start = frame_system::Pallet::<T>::block_number();
+
+    INV4::<T>::open_token_sale(
+        INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())).into(),
+        FeeAssetBalance::Native(1u32.into()),
+        BalanceOf::<T>::max_value().div(2u32.into()),
+        start,
+        start + 10u32.into(),
+    )
+}
+
 fn mock_call<T: Config>() -> Result<PostDispatchInfo, DispatchErrorWithPostInfo<PostDispatchInfo>>
 where
     Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
@@ -114,9 +171,30 @@ where
         None,
         FeeAsset::Native,
         Box::new(frame_system::Call::<T>::remark { remark: vec![0] }.into()),
+        vec![].try_into().unwrap(),
     )
 }
 
+/// Adds `voters` zero balance votes to a proposal, so the cost of voting can be checked against the number of voters.
+fn mock_voters<T: Config>(core_id: T::CoreId, call_hash: T::Hash, voters: u32) {
+    for i in 0..voters {
+        MultisigVotes::<T>::insert(
+            (
+                core_id,
+                call_hash,
+                account::<T::AccountId>("voter", i, SEED),
+            ),
+            Vote::Nay(Zero::zero()),
+        );
+    }
+
+    Multisig::<T>::mutate(core_id, call_hash, |proposal| {
+        if let Some(proposal) = proposal {
+            proposal.tally.voters += voters;
+        }
+    });
+}
+
 fn mock_vote<T: Config>() -> Result<PostDispatchInfo, DispatchErrorWithPostInfo<PostDispatchInfo>>
 where
     Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
@@ -135,9 +213,43 @@ where
         0u32.into(),
         call_hash,
         true,
+        Weight::MAX,
     )
 }
 
+fn fund_core<T: Config>(core_id: T::CoreId)
+where
+    T::AccountId: From<[u8; 32]>,
+{
+    T::Currency::make_free_balance_be(
+        &derive_account::<T>(core_id),
+        T::HandleDeposit::get() + T::Currency::minimum_balance(),
+    );
+}
+
+fn mock_handle<T: Config>(core_id: T::CoreId) -> Result<CoreHandleOf<T>, DispatchError>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance:
+        Sum,
+    <T as frame_system::Config>::RuntimeOrigin: From<INV4Origin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    let handle: CoreHandleOf<T> = vec![b'a'; T::MaxHandleLength::get() as usize]
+        .try_into()
+        .unwrap();
+
+    fund_core::<T>(core_id);
+
+    INV4::<T>::register_handle(
+        INV4Origin::Multisig(MultisigInternalOrigin::new(core_id)).into(),
+        handle.clone(),
+    )?;
+
+    Ok(handle)
+}
+
 benchmarks! {
 
     where_clause {
@@ -152,9 +264,9 @@ benchmarks! {
 }
 
     create_core {
-        let m in 0 .. T::MaxMetadata::get();
+        let m in 0 .. T::MaxDescriptionLength::get();
 
-        let metadata: BoundedVec<u8, T::MaxMetadata> = vec![u8::MAX; m as usize].try_into().unwrap();
+        let metadata = mock_metadata::<T>(m);
         let caller = whitelisted_caller();
         let minimum_support = perbill_one();
         let required_approval = perbill_one();
@@ -166,30 +278,34 @@ benchmarks! {
             assert_last_event::<T>(Event::CoreCreated {
                 core_account: derive_account::<T>(0u32.into()),
                 core_id: 0u32.into(),
-                metadata: metadata.to_vec(),
+                metadata,
                 minimum_support,
                 required_approval
             }.into());
         }
 
     set_parameters {
-        let m in 0 .. T::MaxMetadata::get();
+        let m in 0 .. T::MaxDescriptionLength::get();
 
         mock_core().unwrap();
 
-        let metadata: Option<BoundedVec<u8, T::MaxMetadata>> = Some(vec![u8::MAX; m as usize].try_into().unwrap());
+        let metadata = Some(mock_metadata::<T>(m));
         let minimum_support = Some(perbill_one());
         let required_approval = Some(perbill_one());
         let frozen_tokens = Some(true);
+        let members_only_transfers = Some(true);
+        let minimum_voters = Some(Some(MinimumVoters::Proportion(perbill_one())));
 
-    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), metadata.clone(), minimum_support, required_approval, frozen_tokens)
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), metadata.clone(), minimum_support, required_approval, frozen_tokens, members_only_transfers, minimum_voters)
         verify {
             assert_last_event::<T>(Event::ParametersSet {
                 core_id: 0u32.into(),
-                metadata: metadata.map(|m| m.to_vec()),
+                metadata,
                 minimum_support,
                 required_approval,
-                frozen_tokens
+                frozen_tokens,
+                members_only_transfers,
+                minimum_voters
             }.into());
         }
 
@@ -241,7 +357,7 @@ benchmarks! {
         let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
         let fee_asset = FeeAsset::Native;
 
-    }: _(SystemOrigin::Signed(caller.clone()), core_id, Some(metadata), fee_asset, Box::new(call.clone()))
+    }: _(SystemOrigin::Signed(caller.clone()), core_id, Some(metadata), fee_asset, Box::new(call.clone()), vec![].try_into().unwrap())
         verify {
             assert_last_event::<T>(Event::MultisigVoteStarted {
                 core_id,
@@ -265,7 +381,7 @@ benchmarks! {
         }.into();
         let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
 
-    }: _(SystemOrigin::Signed(caller.clone()), core_id, call_hash, true)
+    }: _(SystemOrigin::Signed(caller.clone()), core_id, call_hash, true, Weight::MAX)
         verify {
             assert_last_event::<T>(Event::MultisigVoteAdded {
                 core_id,
@@ -275,12 +391,7 @@ benchmarks! {
                 current_votes: Tally::<T>::from_parts(
                     (BalanceOf::<T>::max_value().div(4u32.into()) + T::CoreSeedBalance::get()).into(),
                     Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        whitelisted_caller(),
-                        Vote::Aye(T::CoreSeedBalance::get()),
-                    ),
-                                                              (caller, Vote::Aye(BalanceOf::<T>::max_value().div(4u32.into())))
-                    ])).unwrap()
+                    2,
                 ),
                 call_hash,
             }.into());
@@ -331,4 +442,425 @@ benchmarks! {
                 call_hash,
             }.into());
         }
+
+    set_transfer_whitelist {
+        mock_core().unwrap();
+
+        let account: T::AccountId = account("target", 0, SEED);
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), account.clone(), true)
+        verify {
+            assert_last_event::<T>(Event::TransferWhitelistSet {
+                core_id: 0u32.into(),
+                account,
+                whitelisted: true
+            }.into());
+        }
+
+    invite_member {
+        mock_core().unwrap();
+
+        let amount = BalanceOf::<T>::max_value().div(2u32.into());
+        let target: T::AccountId = account("target", 0, SEED);
+        let expires_at = frame_system::Pallet::<T>::block_number() + T::InvitationPeriod::get();
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), amount, target.clone())
+        verify {
+            assert_last_event::<T>(Event::MemberInvited {
+                core_id: 0u32.into(),
+                target,
+                amount,
+                expires_at
+            }.into());
+        }
+
+    accept_invitation {
+        mock_core().unwrap();
+        mock_invite::<T>().unwrap();
+
+        let amount = BalanceOf::<T>::max_value().div(2u32.into());
+        let target: T::AccountId = account("target", 0, SEED);
+
+    }: _(SystemOrigin::Signed(target.clone()), 0u32.into())
+        verify {
+            assert_last_event::<T>(Event::Minted {
+                core_id: 0u32.into(),
+                target,
+                amount
+            }.into());
+        }
+
+    decline_invitation {
+        mock_core().unwrap();
+        mock_invite::<T>().unwrap();
+
+        let target: T::AccountId = account("target", 0, SEED);
+
+    }: _(SystemOrigin::Signed(target.clone()), 0u32.into())
+        verify {
+            assert_last_event::<T>(Event::InvitationDeclined {
+                core_id: 0u32.into(),
+                target
+            }.into());
+        }
+
+    clear_expired_invitation {
+        mock_core().unwrap();
+        mock_invite::<T>().unwrap();
+
+        let target: T::AccountId = account("target", 0, SEED);
+
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::InvitationPeriod::get() + 1u32.into()
+        );
+
+    }: _(SystemOrigin::Signed(whitelisted_caller()), 0u32.into(), target.clone())
+        verify {
+            assert_last_event::<T>(Event::InvitationExpired {
+                core_id: 0u32.into(),
+                target
+            }.into());
+        }
+
+    open_token_sale {
+        mock_core().unwrap();
+
+        let price = FeeAssetBalance::Native(1u32.into());
+        let cap = BalanceOf::<T>::max_value().div(2u32.into());
+        let start = frame_system::Pallet::<T>::block_number();
+        let end = start + 10u32.into();
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), price.clone(), cap, start, end)
+        verify {
+            assert_last_event::<T>(Event::TokenSaleOpened {
+                core_id: 0u32.into(),
+                price,
+                cap,
+                start,
+                end
+            }.into());
+        }
+
+    close_token_sale {
+        mock_core().unwrap();
+        mock_token_sale::<T>().unwrap();
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())))
+        verify {
+            assert_last_event::<T>(Event::TokenSaleClosed {
+                core_id: 0u32.into(),
+                sold: Zero::zero()
+            }.into());
+        }
+
+    buy_tokens {
+        mock_core().unwrap();
+        mock_token_sale::<T>().unwrap();
+
+        let buyer: T::AccountId = account("buyer", 0, SEED);
+        let amount: BalanceOf<T> = T::CoreCreationFee::get();
+
+        T::Currency::make_free_balance_be(&buyer, T::CoreCreationFee::get() + T::CoreCreationFee::get());
+
+    }: _(SystemOrigin::Signed(buyer.clone()), 0u32.into(), amount)
+        verify {
+            assert_last_event::<T>(Event::TokensBought {
+                core_id: 0u32.into(),
+                buyer,
+                amount,
+                cost: FeeAssetBalance::Native(amount)
+            }.into());
+        }
+
+    token_mint_vested {
+        mock_core().unwrap();
+
+        let amount = BalanceOf::<T>::max_value().div(4u32.into());
+        let target: T::AccountId = account("target", 0, SEED);
+        let schedule = VestingSchedule::Linear { start: 1u32.into(), end: 100u32.into() };
+
+        for _ in 1..T::MaxVestingSchedules::get() {
+            INV4::<T>::token_mint_vested(
+                INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())).into(),
+                1u32.into(),
+                target.clone(),
+                schedule,
+            ).unwrap();
+        }
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), amount, target.clone(), schedule)
+        verify {
+            assert_last_event::<T>(Event::Minted {
+                core_id: 0u32.into(),
+                target,
+                amount
+            }.into());
+        }
+
+    vest {
+        mock_core().unwrap();
+
+        let target: T::AccountId = account("target", 0, SEED);
+        let schedule = VestingSchedule::Cliff { unlock_at: 1u32.into() };
+
+        for _ in 0..T::MaxVestingSchedules::get() {
+            INV4::<T>::token_mint_vested(
+                INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())).into(),
+                1u32.into(),
+                target.clone(),
+                schedule,
+            ).unwrap();
+        }
+
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+    }: _(SystemOrigin::Signed(target.clone()), 0u32.into())
+        verify {
+            assert_last_event::<T>(Event::Vested {
+                core_id: 0u32.into(),
+                target,
+                amount: T::MaxVestingSchedules::get().into()
+            }.into());
+        }
+
+    set_max_supply {
+        mock_core().unwrap();
+
+        let max_supply = BalanceOf::<T>::max_value();
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::with_support(0u32.into(), perbill_one())), Some(max_supply), Some(perbill_one()))
+        verify {
+            assert_last_event::<T>(Event::MaxSupplySet {
+                core_id: 0u32.into(),
+                max_supply: Some(max_supply),
+                supermajority: perbill_one()
+            }.into());
+        }
+
+    set_proposal_limits {
+        mock_core().unwrap();
+
+        let limits = Some(ProposalLimits {
+            min_balance: T::CoreSeedBalance::get(),
+            max_open_proposals: 1,
+            cooldown: 10u32.into(),
+            deposit: T::CoreCreationFee::get(),
+            slash_deposit: true,
+        });
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), limits.clone())
+        verify {
+            assert_last_event::<T>(Event::ProposalLimitsSet {
+                core_id: 0u32.into(),
+                limits
+            }.into());
+        }
+
+    amend_multisig_metadata {
+        let m in 0 .. T::MaxMetadata::get();
+
+        mock_core().unwrap();
+        mock_mint().unwrap();
+        mock_mint_2().unwrap();
+        mock_call().unwrap();
+
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value().div(4u32.into()));
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0]
+        }.into();
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
+        let metadata: Option<BoundedVec<u8, T::MaxMetadata>> = Some(vec![u8::MAX; m as usize].try_into().unwrap());
+
+    }: _(SystemOrigin::Signed(caller), 0u32.into(), call_hash, metadata.clone())
+        verify {
+            assert_last_event::<T>(Event::MultisigMetadataAmended {
+                core_id: 0u32.into(),
+                call_hash,
+                metadata: metadata.map(|m| m.to_vec())
+            }.into());
+        }
+
+    execute_multisig {
+        mock_core().unwrap();
+        mock_mint().unwrap();
+        mock_mint_2().unwrap();
+        mock_call().unwrap();
+
+        let caller: T::AccountId = account("target", 0, SEED);
+        let core_id: T::CoreId = 0u32.into();
+        let dependency: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0]
+        }.into();
+        let dependency_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&dependency.clone());
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![1]
+        }.into();
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
+
+        INV4::<T>::operate_multisig(
+            SystemOrigin::Signed(whitelisted_caller()).into(),
+            core_id,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![dependency_hash].try_into().unwrap(),
+        ).unwrap();
+        let voters: [T::AccountId; 2] = [caller.clone(), account("target1", 1, SEED + 1)];
+        for hash in [call_hash, dependency_hash] {
+            for voter in voters.clone() {
+                INV4::<T>::vote_multisig(SystemOrigin::Signed(voter).into(), core_id, hash, true, Weight::MAX).unwrap();
+            }
+        }
+
+    }: _(SystemOrigin::Signed(caller.clone()), core_id, call_hash, Weight::MAX)
+        verify {
+            assert_last_event::<T>(Event::MultisigExecuted {
+                core_id,
+                executor_account: derive_account::<T>(core_id),
+                voter: caller,
+                call_hash,
+                calls: vec![call],
+                failed_call: None,
+                result: Ok(()),
+            }.into());
+        }
+
+    clear_multisig_votes {
+        let n in 0 .. MAX_VOTERS;
+
+        mock_core().unwrap();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let core_id: T::CoreId = 0u32.into();
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0]
+        }.into();
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
+        mock_voters::<T>(core_id, call_hash, n);
+
+    }: _(SystemOrigin::Signed(caller), core_id, call_hash, n)
+        verify {
+            assert_last_event::<T>(Event::MultisigVotesCleared {
+                core_id,
+                call_hash,
+                cleared: n,
+                complete: true,
+            }.into());
+        }
+
+    as_derivative {
+        mock_core().unwrap();
+
+        let core_id: T::CoreId = 0u32.into();
+        let index = 0u16;
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0]
+        }.into();
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(core_id)), index, Box::new(call))
+        verify {
+            assert_last_event::<T>(Event::DerivativeDispatched {
+                core_id,
+                index,
+                account: INV4::<T>::derive_core_derivative_account(core_id, index),
+                result: Ok(()),
+            }.into());
+        }
+
+    set_remote_member {
+        mock_core().unwrap();
+
+        let core_id: T::CoreId = 0u32.into();
+        let member: T::AccountId = account("target", 0, SEED);
+        let location = MultiLocation::new(1, X2(Parachain(2000), AccountId32 { network: None, id: [0u8; 32] }));
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(core_id)), Box::new(VersionedMultiLocation::from(location)), Some(member.clone()))
+        verify {
+            assert_last_event::<T>(Event::RemoteMemberSet {
+                core_id,
+                location,
+                member: Some(member),
+            }.into());
+        }
+
+    vote_multisig_remote {
+        mock_core().unwrap();
+        mock_mint().unwrap();
+        mock_mint_2().unwrap();
+        mock_call().unwrap();
+
+        let caller: T::AccountId = account("target", 0, SEED);
+        let core_id: T::CoreId = 0u32.into();
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+            remark: vec![0]
+        }.into();
+        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call.clone());
+
+        let origin = T::RemoteVoteOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let location = T::RemoteVoteOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+        RemoteMembers::<T>::insert(core_id, location, caller.clone());
+
+    }: _<T::RuntimeOrigin>(origin, core_id, call_hash, true, Weight::MAX)
+        verify {
+            assert_last_event::<T>(Event::MultisigVoteAdded {
+                core_id,
+                executor_account: derive_account::<T>(core_id),
+                voter: caller.clone(),
+                votes_added:  Vote::Aye(BalanceOf::<T>::max_value().div(4u32.into())),
+                current_votes: Tally::<T>::from_parts(
+                    (BalanceOf::<T>::max_value().div(4u32.into()) + T::CoreSeedBalance::get()).into(),
+                    Zero::zero(),
+                    2,
+                ),
+                call_hash,
+            }.into());
+        }
+
+    register_handle {
+        mock_core().unwrap();
+
+        let core_id: T::CoreId = 0u32.into();
+        let handle: CoreHandleOf<T> = vec![b'a'; T::MaxHandleLength::get() as usize].try_into().unwrap();
+        fund_core::<T>(core_id);
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(core_id)), handle.clone())
+        verify {
+            assert_last_event::<T>(Event::HandleRegistered {
+                core_id,
+                handle,
+            }.into());
+        }
+
+    transfer_handle {
+        mock_core().unwrap();
+        mock_core().unwrap();
+
+        let core_id: T::CoreId = 0u32.into();
+        let target_core_id: T::CoreId = 1u32.into();
+        let handle = mock_handle::<T>(core_id)?;
+        fund_core::<T>(target_core_id);
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(core_id)), target_core_id)
+        verify {
+            assert_last_event::<T>(Event::HandleTransferred {
+                handle,
+                from: core_id,
+                to: target_core_id,
+            }.into());
+        }
+
+    release_handle {
+        mock_core().unwrap();
+
+        let core_id: T::CoreId = 0u32.into();
+        let handle = mock_handle::<T>(core_id)?;
+
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(core_id)))
+        verify {
+            assert_last_event::<T>(Event::HandleReleased {
+                core_id,
+                handle,
+            }.into());
+        }
 }
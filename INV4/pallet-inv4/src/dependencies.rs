@@ -0,0 +1,108 @@
+//! Proposal Dependencies.
+//!
+//! ## Overview
+//!
+//! This module allows multisig proposals to depend on other proposals of the same core, identified by their call hash,
+//! so multi-step operations can be executed in order.
+//!
+//! A proposal that meets its thresholds is only executed once all of its dependencies executed successfully,
+//! otherwise its execution is deferred until `execute_multisig` is called after the dependencies are done.
+//! If any dependency fails or is canceled, the proposal is canceled the next time it's voted on or executed.
+//!
+//! Results are only kept while there are proposals depending on them, see [`ProposalResults`].
+//!
+//! ### Core functionalities:
+//! - `dependency_status`: Checks if the dependencies of a proposal are done.
+//! - `register_dependencies`: Tracks the dependencies of a new proposal.
+//! - `resolve_dependencies`: Stops tracking the dependencies of a proposal leaving the voting stage.
+//! - `record_result`: Keeps the result of an executed or canceled proposal for its dependents, emitting
+//!   `MultisigDependentsFailed` if they can't be executed anymore.
+
+use super::pallet::*;
+use frame_support::pallet_prelude::*;
+use sp_runtime::traits::Saturating;
+
+/// Status of the dependencies of a proposal.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum DependencyStatus<Hash> {
+    /// All dependencies executed successfully.
+    Ready,
+    /// Some dependencies didn't execute yet.
+    Pending,
+    /// A dependency failed or was canceled.
+    Failed(Hash),
+}
+
+impl<T: Config> Pallet<T> {
+    /// Checks if the dependencies of a proposal are done.
+    pub fn dependency_status(
+        core_id: T::CoreId,
+        depends_on: &[T::Hash],
+    ) -> DependencyStatus<T::Hash> {
+        let mut status = DependencyStatus::Ready;
+
+        for dependency in depends_on {
+            match ProposalResults::<T>::get(core_id, dependency) {
+                Some(true) => (),
+                Some(false) => return DependencyStatus::Failed(*dependency),
+                None => status = DependencyStatus::Pending,
+            }
+        }
+
+        status
+    }
+
+    /// Tracks the dependencies of a new proposal, making sure they're either pending or already resolved.
+    pub(crate) fn register_dependencies(
+        core_id: T::CoreId,
+        depends_on: &[T::Hash],
+    ) -> DispatchResult {
+        for dependency in depends_on {
+            ensure!(
+                Multisig::<T>::contains_key(core_id, dependency)
+                    || ProposalResults::<T>::contains_key(core_id, dependency),
+                Error::<T>::DependencyNotFound
+            );
+
+            Dependents::<T>::mutate(core_id, dependency, |count| {
+                *count = count.saturating_add(1)
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Stops tracking the dependencies of a proposal leaving the voting stage,
+    /// dropping the results nothing depends on anymore.
+    pub(crate) fn resolve_dependencies(core_id: T::CoreId, depends_on: &[T::Hash]) {
+        for dependency in depends_on {
+            let remaining = Dependents::<T>::mutate_exists(core_id, dependency, |count| {
+                let remaining = count.unwrap_or_default().saturating_sub(1);
+                *count = Some(remaining).filter(|c| *c > 0);
+                remaining
+            });
+
+            if remaining == 0 {
+                ProposalResults::<T>::remove(core_id, dependency);
+            }
+        }
+    }
+
+    /// Keeps the result of an executed or canceled proposal if other proposals depend on it,
+    /// notifying that they can't be executed anymore if it failed.
+    pub(crate) fn record_result(core_id: T::CoreId, call_hash: T::Hash, success: bool) {
+        let dependents = Dependents::<T>::get(core_id, call_hash);
+
+        if dependents > 0 {
+            ProposalResults::<T>::insert(core_id, call_hash, success);
+
+            if !success {
+                Self::deposit_event(Event::MultisigDependentsFailed {
+                    core_id,
+                    call_hash,
+                    dependents,
+                });
+            }
+        }
+    }
+}
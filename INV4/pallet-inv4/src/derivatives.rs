@@ -0,0 +1,64 @@
+//! Derivative Sub-Accounts.
+//!
+//! ## Overview
+//!
+//! A core has a single account, derived from its id, see [`crate::account_derivation`].
+//! This module lets cores control any number of derivative sub-accounts, identified by an index within the core,
+//! so funds can be kept apart, e.g. one sub-account per project.
+//!
+//! Sub-accounts are derived deterministically from the core and the index, both locally and in other chains
+//! through the sub-account's XCM location. Their accounts can be looked up with `lookup_derivative`.
+//!
+//! ### Core functionalities:
+//! - `as_derivative`: Dispatches a call from one of the core's sub-accounts (called by a core origin). The call's
+//!   result is reported in the `DerivativeDispatched` event.
+
+use super::pallet::*;
+use crate::{
+    account_derivation::CoreAccountDerivation,
+    origin::{ensure_multisig, INV4Origin},
+    WeightInfo,
+};
+use frame_support::{
+    dispatch::{extract_actual_weight, GetDispatchInfo},
+    pallet_prelude::*,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use sp_runtime::traits::Dispatchable;
+use sp_std::boxed::Box;
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    T::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the as_derivative call.
+    pub(crate) fn inner_as_derivative(
+        origin: OriginFor<T>,
+        index: u16,
+        call: Box<<T as Config>::RuntimeCall>,
+    ) -> DispatchResultWithPostInfo {
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        let account = Self::derive_core_derivative_account(core_id, index);
+
+        let info = call.get_dispatch_info();
+        let result = call.dispatch(RawOrigin::Signed(account.clone()).into());
+
+        // The base weight is always charged along with the actual weight of the call
+        let weight = <T as Config>::WeightInfo::as_derivative()
+            .saturating_add(extract_actual_weight(&result, &info));
+
+        // The call's result is reported in the event rather than as an error, so the event isn't reverted with it
+        Self::deposit_event(Event::DerivativeDispatched {
+            core_id,
+            index,
+            account,
+            result: result.map(|_| ()).map_err(|e| e.error),
+        });
+
+        Ok(Some(weight).into())
+    }
+}
@@ -4,60 +4,245 @@
 //!
 //! This module employs a custom `MultisigInternalOrigin` to ensure calls originate
 //! from the multisig account itself, automating fee payments. The `dispatch_call` function
-//! includes pre and post dispatch handling for streamlined fee management within the multisig context.
+//! includes pre and post dispatch handling for streamlined fee management within the multisig context,
+//! dispatching batches of calls atomically and charging fees once for the whole batch.
 
 use crate::{
-    fee_handling::{FeeAsset, MultisigFeeHandler},
+    fee_handling::{FeeAsset, FeeAssetBalance, FeeAssetBalanceOf, MultisigFeeHandler},
     origin::{INV4Origin, MultisigInternalOrigin},
     Config, Error,
 };
-use frame_support::{dispatch::GetDispatchInfo, pallet_prelude::*};
+use frame_support::{
+    dispatch::{
+        extract_actual_weight, DispatchClass, DispatchErrorWithPostInfo, DispatchInfo,
+        GetDispatchInfo, Pays, PostDispatchInfo,
+    },
+    pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
+    traits::{fungibles::Inspect, Currency},
+};
 
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::{
+    traits::{Dispatchable, Saturating},
+    Perbill,
+};
 
-/// Dispatch a call executing pre/post dispatch for proper fee handling.
+/// Dispatch a batch of calls atomically executing pre/post dispatch for proper fee handling.
+///
+/// Fees are charged once for the whole batch, based on the aggregated weight and length of the calls.
+/// If any call fails, the effects of the whole batch are reverted and the remaining calls are not dispatched,
+/// only the weight of the calls that were dispatched is charged.
+///
+/// Returns the result of the batch along with the index of the call that failed, reverting the batch.
+/// The post dispatch info of the batch always has the actual weight of the dispatched calls set.
 pub fn dispatch_call<T: Config>(
     core_id: <T as Config>::CoreId,
+    support: Perbill,
     fee_asset: &FeeAsset,
-    call: <T as Config>::RuntimeCall,
-) -> DispatchResultWithPostInfo
+    calls: &[<T as Config>::RuntimeCall],
+) -> (DispatchResultWithPostInfo, Option<u32>)
 where
     T::AccountId: From<[u8; 32]>,
 {
+    let mut failed_call = None;
+
+    // Nothing to charge fees for.
+    let Some(first_call) = calls.first() else {
+        return (Ok(Some(Weight::zero()).into()), failed_call);
+    };
+
     // Create new custom origin as the multisig.
-    let internal_origin = MultisigInternalOrigin::new(core_id);
+    let internal_origin = MultisigInternalOrigin::with_support(core_id, support);
     let multisig_account = internal_origin.to_account_id();
-    let origin = INV4Origin::Multisig(internal_origin).into();
 
-    let info = call.get_dispatch_info();
-    let len = call.encode().len();
+    let info = batch_dispatch_info::<T>(calls);
+    let len = calls.iter().map(|call| call.encoded_size()).sum();
 
     // Execute pre dispatch using the multisig account instead of the extrinsic caller.
-    let pre = <T::FeeCharger as MultisigFeeHandler<T>>::pre_dispatch(
+    // Fees are computed from the aggregated info and length, the first call is only passed along for context.
+    let pre = match <T::FeeCharger as MultisigFeeHandler<T>>::pre_dispatch(
         fee_asset,
         &multisig_account,
-        &call,
+        first_call,
         &info,
         len,
-    )
-    .map_err(|_| Error::<T>::CallFeePaymentFailed)?;
+    ) {
+        Ok(pre) => pre,
+        Err(_) => {
+            return (
+                Err(DispatchErrorWithPostInfo {
+                    post_info: Some(Weight::zero()).into(),
+                    error: Error::<T>::CallFeePaymentFailed.into(),
+                }),
+                failed_call,
+            )
+        }
+    };
+
+    let mut actual_weight = Weight::zero();
+
+    let dispatch_result = with_transaction(|| {
+        for (index, call) in calls.iter().enumerate() {
+            let call_info = call.get_dispatch_info();
+            let call_result = call
+                .clone()
+                .dispatch(INV4Origin::Multisig(internal_origin.clone()).into());
 
-    let dispatch_result = call.dispatch(origin);
+            actual_weight =
+                actual_weight.saturating_add(extract_actual_weight(&call_result, &call_info));
 
-    let post = match dispatch_result {
-        Ok(p) => p,
-        Err(e) => e.post_info,
+            // Revert the calls dispatched so far and stop at the first error.
+            if let Err(e) = call_result {
+                failed_call = Some(index as u32);
+                return TransactionOutcome::Rollback(Err(e.error));
+            }
+        }
+
+        TransactionOutcome::Commit(Ok(()))
+    });
+
+    let post = PostDispatchInfo {
+        actual_weight: Some(actual_weight),
+        pays_fee: info.pays_fee,
     };
 
-    <T::FeeCharger as MultisigFeeHandler<T>>::post_dispatch(
+    if <T::FeeCharger as MultisigFeeHandler<T>>::post_dispatch(
         fee_asset,
         Some(pre),
         &info,
         &post,
         len,
-        &dispatch_result.map(|_| ()).map_err(|e| e.error),
+        &dispatch_result,
     )
-    .map_err(|_| Error::<T>::CallFeePaymentFailed)?;
+    .is_err()
+    {
+        return (
+            Err(DispatchErrorWithPostInfo {
+                post_info: post,
+                error: Error::<T>::CallFeePaymentFailed.into(),
+            }),
+            failed_call,
+        );
+    }
+
+    (
+        dispatch_result
+            .map(|_| post)
+            .map_err(|error| DispatchErrorWithPostInfo {
+                post_info: post,
+                error,
+            }),
+        failed_call,
+    )
+}
 
-    dispatch_result
+/// Actual weight of the calls dispatched by [`dispatch_call`].
+pub fn dispatched_weight(result: &DispatchResultWithPostInfo) -> Weight {
+    match result {
+        Ok(post_info) => post_info.actual_weight,
+        Err(e) => e.post_info.actual_weight,
+    }
+    .unwrap_or_default()
+}
+
+/// Fee charged to the multisig account for dispatching a batch of calls with the given actual weight.
+///
+/// The fee handler is run inside a storage transaction that is always rolled back, so nothing is charged.
+/// Returns `None` if the multisig account can't pay the fee.
+pub fn charged_fee<T: Config>(
+    core_id: <T as Config>::CoreId,
+    fee_asset: &FeeAsset,
+    calls: &[<T as Config>::RuntimeCall],
+    actual_weight: Weight,
+) -> Option<FeeAssetBalanceOf<T>>
+where
+    T::AccountId: From<[u8; 32]>,
+{
+    let first_call = calls.first()?;
+
+    let multisig_account = MultisigInternalOrigin::<T>::new(core_id).to_account_id();
+
+    let info = batch_dispatch_info::<T>(calls);
+    let len = calls.iter().map(|call| call.encoded_size()).sum();
+
+    let post = PostDispatchInfo {
+        actual_weight: Some(actual_weight),
+        pays_fee: info.pays_fee,
+    };
+
+    let charge = || {
+        let pre = <T::FeeCharger as MultisigFeeHandler<T>>::pre_dispatch(
+            fee_asset,
+            &multisig_account,
+            first_call,
+            &info,
+            len,
+        )?;
+
+        <T::FeeCharger as MultisigFeeHandler<T>>::post_dispatch(
+            fee_asset,
+            Some(pre),
+            &info,
+            &post,
+            len,
+            &Ok(()),
+        )
+    };
+
+    with_transaction(|| {
+        let fee = match fee_asset {
+            FeeAsset::Native => {
+                let before = <T as Config>::Currency::free_balance(&multisig_account);
+
+                charge().ok().map(|_| {
+                    FeeAssetBalance::Native(
+                        before.saturating_sub(<T as Config>::Currency::free_balance(
+                            &multisig_account,
+                        )),
+                    )
+                })
+            }
+            FeeAsset::Relay => {
+                let before =
+                    <T as Config>::Tokens::balance(T::RelayAssetId::get(), &multisig_account);
+
+                charge().ok().map(|_| {
+                    FeeAssetBalance::Relay(before.saturating_sub(<T as Config>::Tokens::balance(
+                        T::RelayAssetId::get(),
+                        &multisig_account,
+                    )))
+                })
+            }
+        };
+
+        TransactionOutcome::Rollback(Ok::<_, DispatchError>(fee))
+    })
+    .ok()
+    .flatten()
+}
+
+/// Aggregates the dispatch info of a batch of calls.
+///
+/// The batch is only operational if all of its calls are, and it only pays fees if any of its calls does.
+fn batch_dispatch_info<T: Config>(calls: &[<T as Config>::RuntimeCall]) -> DispatchInfo {
+    calls.iter().map(|call| call.get_dispatch_info()).fold(
+        DispatchInfo {
+            weight: Weight::zero(),
+            class: DispatchClass::Operational,
+            pays_fee: Pays::No,
+        },
+        |batch, info| DispatchInfo {
+            weight: batch.weight.saturating_add(info.weight),
+            class: if info.class == DispatchClass::Operational {
+                batch.class
+            } else {
+                DispatchClass::Normal
+            },
+            pays_fee: if info.pays_fee == Pays::Yes {
+                Pays::Yes
+            } else {
+                batch.pays_fee
+            },
+        },
+    )
 }
@@ -0,0 +1,86 @@
+//! Proposal Dry Runs.
+//!
+//! ## Overview
+//!
+//! Lets members check what a call would do if dispatched by the core before voting on it,
+//! e.g. whether the core has enough balance for it.
+//!
+//! The call is dispatched with the core's origin inside a storage transaction that is always rolled back,
+//! as if it was proposed and passed with full support, so no state is changed.
+//!
+//! ### Core functionalities:
+//! - `dry_run_call`: Dispatches a call as the core and reports its result, weight, fee and events.
+
+use super::pallet::*;
+use crate::{
+    dispatch,
+    fee_handling::{FeeAsset, FeeAssetBalanceOf},
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
+};
+use scale_info::TypeInfo;
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
+
+/// Outcome of dry-running a call as a core.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct DryRunResult<Event, Fee> {
+    /// Result of dispatching the call, including failures to pay the fee.
+    pub result: DispatchResult,
+    /// Actual weight of the dispatched call.
+    pub actual_weight: Weight,
+    /// Fee charged to the core in the chosen fee asset, `None` if the core can't pay it.
+    pub fee: Option<Fee>,
+    /// Events emitted while dispatching the call.
+    pub events: Vec<Event>,
+}
+
+pub type DryRunResultOf<T> =
+    DryRunResult<<T as frame_system::Config>::RuntimeEvent, FeeAssetBalanceOf<T>>;
+
+impl<T: Config> Pallet<T>
+where
+    T::AccountId: From<[u8; 32]>,
+{
+    /// Dispatches a call with the core's origin and reports what it would do, rolling back all of its effects.
+    pub fn dry_run_call(
+        core_id: T::CoreId,
+        call: CallOf<T>,
+        fee_asset: FeeAsset,
+    ) -> Result<DryRunResultOf<T>, DispatchError> {
+        ensure!(
+            CoreStorage::<T>::contains_key(core_id),
+            Error::<T>::CoreNotFound
+        );
+
+        let calls = [call];
+
+        let (result, actual_weight, events) = with_transaction(|| {
+            let event_count = frame_system::Pallet::<T>::event_count() as usize;
+
+            let (dispatch_result, _) =
+                dispatch::dispatch_call::<T>(core_id, Perbill::one(), &fee_asset, &calls);
+
+            let events = frame_system::Pallet::<T>::read_events_no_consensus()
+                .skip(event_count)
+                .map(|record| record.event)
+                .collect::<Vec<_>>();
+
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>((
+                dispatch_result.map(|_| ()).map_err(|e| e.error),
+                dispatch::dispatched_weight(&dispatch_result),
+                events,
+            )))
+        })?;
+
+        Ok(DryRunResult {
+            result,
+            actual_weight,
+            fee: dispatch::charged_fee::<T>(core_id, &fee_asset, &calls, actual_weight),
+            events,
+        })
+    }
+}
@@ -5,10 +5,13 @@
 //! Defines how transaction fees are charged to the multisig account.
 //! This trait requires proper runtime implementation to allow the usage of native or non-native assets.
 
-use crate::Config;
+use crate::{BalanceOf, Config};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-    traits::{fungibles::Credit, Currency},
+    traits::{
+        fungibles::{Credit, Inspect},
+        Currency,
+    },
     unsigned::TransactionValidityError,
 };
 use scale_info::TypeInfo;
@@ -41,6 +44,18 @@ pub enum FeeAssetNegativeImbalance<NativeNegativeImbalance, RelayNegativeImbalan
     Relay(RelayNegativeImbalance),
 }
 
+/// Represents an amount of either fee asset, such as the fee charged for dispatching a proposal.
+#[derive(Clone, TypeInfo, Encode, Decode, MaxEncodedLen, Debug, PartialEq, Eq)]
+pub enum FeeAssetBalance<NativeBalance, RelayBalance> {
+    Native(NativeBalance),
+    Relay(RelayBalance),
+}
+
+pub type FeeAssetBalanceOf<T> = FeeAssetBalance<
+    BalanceOf<T>,
+    <<T as Config>::Tokens as Inspect<<T as frame_system::Config>::AccountId>>::Balance,
+>;
+
 /// Fee handler trait.
 ///
 /// This should be implemented properly in the runtime to account for native and non-native assets.
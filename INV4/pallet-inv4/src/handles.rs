@@ -0,0 +1,173 @@
+//! Core Handles.
+//!
+//! ## Overview
+//!
+//! Cores can register a unique human-readable handle, e.g. `invarch-dao`, so users don't have to deal with the
+//! core's account. Handles resolve to the core's account through `MultiAddress::Raw` in [`crate::INV4Lookup`].
+//!
+//! Registering a handle reserves `HandleDeposit` from the core account, returned once the handle is released.
+//! Handles are made of lowercase ASCII letters, digits and dashes, and each core can hold a single handle.
+//!
+//! ### Core functionalities:
+//! - `register_handle`: Registers a handle for the core, reserving the deposit (called by a core origin).
+//! - `transfer_handle`: Transfers the core's handle to another core along with its deposit (called by a core origin).
+//! - `release_handle`: Releases the core's handle, returning the deposit (called by a core origin).
+
+use super::pallet::*;
+use crate::{
+    account_derivation::CoreAccountDerivation,
+    origin::{ensure_multisig, INV4Origin},
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{BalanceStatus, ReservableCurrency},
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
+
+pub type CoreHandleOf<T> = BoundedVec<u8, <T as Config>::MaxHandleLength>;
+
+/// Handle registered by a core and the deposit reserved for it.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct HandleRecord<Handle, Balance> {
+    pub handle: Handle,
+    /// Deposit reserved from the core account.
+    pub deposit: Balance,
+}
+
+pub type HandleRecordOf<T> = HandleRecord<CoreHandleOf<T>, BalanceOf<T>>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    T::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the register_handle call.
+    pub(crate) fn inner_register_handle(
+        origin: OriginFor<T>,
+        handle: CoreHandleOf<T>,
+    ) -> DispatchResult {
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(core_id),
+            Error::<T>::CoreNotFound
+        );
+
+        ensure!(Self::is_valid_handle(&handle), Error::<T>::InvalidHandle);
+
+        ensure!(
+            !CoreHandles::<T>::contains_key(core_id),
+            Error::<T>::CoreAlreadyHasHandle
+        );
+
+        ensure!(
+            !HandleOwners::<T>::contains_key(&handle),
+            Error::<T>::HandleAlreadyTaken
+        );
+
+        let deposit = T::HandleDeposit::get();
+
+        <T as Config>::Currency::reserve(&Self::derive_core_account(core_id), deposit)?;
+
+        HandleOwners::<T>::insert(&handle, core_id);
+        CoreHandles::<T>::insert(
+            core_id,
+            HandleRecord {
+                handle: handle.clone(),
+                deposit,
+            },
+        );
+
+        Self::deposit_event(Event::HandleRegistered { core_id, handle });
+
+        Ok(())
+    }
+
+    /// Inner function for the transfer_handle call.
+    pub(crate) fn inner_transfer_handle(
+        origin: OriginFor<T>,
+        target_core_id: T::CoreId,
+    ) -> DispatchResult {
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(target_core_id),
+            Error::<T>::CoreNotFound
+        );
+
+        ensure!(
+            !CoreHandles::<T>::contains_key(target_core_id),
+            Error::<T>::CoreAlreadyHasHandle
+        );
+
+        let record = CoreHandles::<T>::take(core_id).ok_or(Error::<T>::HandleNotFound)?;
+
+        // The deposit moves along with the handle, so the new owner gets it back once released
+        let remaining = <T as Config>::Currency::repatriate_reserved(
+            &Self::derive_core_account(core_id),
+            &Self::derive_core_account(target_core_id),
+            record.deposit,
+            BalanceStatus::Reserved,
+        )?;
+        let deposit = record.deposit.saturating_sub(remaining);
+
+        HandleOwners::<T>::insert(&record.handle, target_core_id);
+        CoreHandles::<T>::insert(
+            target_core_id,
+            HandleRecord {
+                handle: record.handle.clone(),
+                deposit,
+            },
+        );
+
+        Self::deposit_event(Event::HandleTransferred {
+            handle: record.handle,
+            from: core_id,
+            to: target_core_id,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the release_handle call.
+    pub(crate) fn inner_release_handle(origin: OriginFor<T>) -> DispatchResult {
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        let record = CoreHandles::<T>::take(core_id).ok_or(Error::<T>::HandleNotFound)?;
+
+        HandleOwners::<T>::remove(&record.handle);
+
+        <T as Config>::Currency::unreserve(&Self::derive_core_account(core_id), record.deposit);
+
+        Self::deposit_event(Event::HandleReleased {
+            core_id,
+            handle: record.handle,
+        });
+
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Checks that a handle is not empty and only contains lowercase ASCII letters, digits and dashes.
+    pub fn is_valid_handle(handle: &[u8]) -> bool {
+        !handle.is_empty()
+            && handle
+                .iter()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-')
+    }
+
+    /// Retrieves the core owning a handle.
+    pub fn core_by_handle(handle: &[u8]) -> Option<T::CoreId> {
+        CoreHandleOf::<T>::try_from(handle.to_vec())
+            .ok()
+            .and_then(HandleOwners::<T>::get)
+    }
+}
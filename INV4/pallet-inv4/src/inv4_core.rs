@@ -7,6 +7,10 @@
 //!  - `inner_create_core`: Sets up a new core, deriving its AccountId, distributing voting tokens, and handling creation fees.
 //!  - `inner_set_parameters`: Updates the core's operational rules.
 //! - `is_asset_frozen`: Utility function for checking if a core's voting asset is frozen (can't be transferred by the owner).
+//! - `ensure_transfer_allowed`: Utility function for checking if a core's voting asset can be transferred to a given account.
+//! - `inner_set_transfer_whitelist`: Adds or removes accounts from the core's whitelist of transfer recipients.
+//! - `inner_set_max_supply`: Updates the core's voting token supply cap, requiring the core's supermajority.
+//! - `ensure_within_max_supply`: Utility function for checking if an amount can be minted without exceeding the supply cap.
 
 use super::pallet::*;
 use crate::{
@@ -17,19 +21,25 @@ use crate::{
 use frame_support::{
     pallet_prelude::*,
     traits::{
-        fungibles::{Balanced, Mutate},
+        fungibles::{Balanced, Inspect, Mutate},
         tokens::{Fortitude, Precision, Preservation},
         Currency, ExistenceRequirement, WithdrawReasons,
     },
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
-use primitives::CoreInfo;
+use primitives::{CoreInfo, CoreMetadata, MinimumVoters};
 use sp_arithmetic::traits::{CheckedAdd, One};
-use sp_runtime::Perbill;
+use sp_runtime::{ArithmeticError, DispatchError, Perbill, TokenError};
 
 pub type CoreIndexOf<T> = <T as Config>::CoreId;
 
-pub type CoreMetadataOf<T> = BoundedVec<u8, <T as Config>::MaxMetadata>;
+pub type CoreMetadataOf<T> = CoreMetadata<
+    BoundedVec<u8, <T as Config>::MaxNameLength>,
+    BoundedVec<u8, <T as Config>::MaxDescriptionLength>,
+    BoundedVec<u8, <T as Config>::MaxImageUrlLength>,
+    BoundedVec<u8, <T as Config>::MaxWebsiteLength>,
+    <T as frame_system::Config>::Hash,
+>;
 
 impl<T: Config> Pallet<T>
 where
@@ -40,7 +50,7 @@ where
     /// Inner function for the create_core call.
     pub(crate) fn inner_create_core(
         origin: OriginFor<T>,
-        metadata: BoundedVec<u8, T::MaxMetadata>,
+        metadata: CoreMetadataOf<T>,
         minimum_support: Perbill,
         required_approval: Perbill,
         creation_fee_asset: FeeAsset,
@@ -68,7 +78,12 @@ where
                 metadata: metadata.clone(),
                 minimum_support,
                 required_approval,
+                minimum_voters: None,
                 frozen_tokens: true,
+                members_only_transfers: false,
+                // Supply is uncapped by default and changing that requires unanimity
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
             };
 
             // Charge creation fee from the caller
@@ -100,7 +115,7 @@ where
 
             Self::deposit_event(Event::CoreCreated {
                 core_account,
-                metadata: metadata.to_vec(),
+                metadata,
                 core_id: current_id,
                 minimum_support,
                 required_approval,
@@ -113,10 +128,12 @@ where
     /// Inner function for the set_parameters call.
     pub(crate) fn inner_set_parameters(
         origin: OriginFor<T>,
-        metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+        metadata: Option<CoreMetadataOf<T>>,
         minimum_support: Option<Perbill>,
         required_approval: Option<Perbill>,
         frozen_tokens: Option<bool>,
+        members_only_transfers: Option<bool>,
+        minimum_voters: Option<Option<MinimumVoters>>,
     ) -> DispatchResult {
         let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
         let core_id = core_origin.id;
@@ -140,22 +157,138 @@ where
                 c.frozen_tokens = f;
             }
 
+            if let Some(mo) = members_only_transfers {
+                c.members_only_transfers = mo;
+            }
+
+            if let Some(mv) = minimum_voters {
+                c.minimum_voters = mv;
+            }
+
             *core = Some(c);
 
             Self::deposit_event(Event::ParametersSet {
                 core_id,
-                metadata: metadata.map(|m| m.to_vec()),
+                metadata,
                 minimum_support,
                 required_approval,
                 frozen_tokens,
+                members_only_transfers,
+                minimum_voters,
             });
 
             Ok(())
         })
     }
 
+    /// Inner function for the set_transfer_whitelist call.
+    pub(crate) fn inner_set_transfer_whitelist(
+        origin: OriginFor<T>,
+        account: T::AccountId,
+        whitelisted: bool,
+    ) -> DispatchResult {
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(core_id),
+            Error::<T>::CoreNotFound
+        );
+
+        if whitelisted {
+            TransferWhitelist::<T>::insert(core_id, &account, ());
+        } else {
+            TransferWhitelist::<T>::remove(core_id, &account);
+        }
+
+        Self::deposit_event(Event::TransferWhitelistSet {
+            core_id,
+            account,
+            whitelisted,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the set_max_supply call.
+    pub(crate) fn inner_set_max_supply(
+        origin: OriginFor<T>,
+        max_supply: Option<BalanceOf<T>>,
+        supermajority: Option<Perbill>,
+    ) -> DispatchResult {
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        CoreStorage::<T>::try_mutate(core_id, |core| {
+            let c = core.as_mut().ok_or(Error::<T>::CoreNotFound)?;
+
+            // The proposal must have been approved by the core's current supermajority
+            ensure!(
+                core_origin.support >= c.max_supply_supermajority,
+                Error::<T>::SupermajorityNotReached
+            );
+
+            // The new cap can't be below the current supply
+            if let Some(max) = max_supply {
+                ensure!(
+                    T::AssetsProvider::total_issuance(core_id) <= max,
+                    Error::<T>::MaxSupplyExceeded
+                );
+            }
+
+            c.max_supply = max_supply;
+
+            if let Some(s) = supermajority {
+                c.max_supply_supermajority = s;
+            }
+
+            Self::deposit_event(Event::MaxSupplySet {
+                core_id,
+                max_supply,
+                supermajority: c.max_supply_supermajority,
+            });
+
+            Ok(())
+        })
+    }
+}
+
+impl<T: Config> Pallet<T> {
     /// Checks if the voting asset is frozen.
     pub fn is_asset_frozen(core_id: T::CoreId) -> Option<bool> {
         CoreStorage::<T>::get(core_id).map(|c| c.frozen_tokens)
     }
+
+    /// Checks if the voting asset can be transferred to `to`.
+    ///
+    /// Frozen tokens can't be transferred at all, while tokens of a core with `members_only_transfers` set
+    /// can only be transferred to existing members or to accounts in the core's transfer whitelist.
+    pub fn ensure_transfer_allowed(core_id: T::CoreId, to: &T::AccountId) -> DispatchResult {
+        match CoreStorage::<T>::get(core_id) {
+            Some(core) if core.frozen_tokens => Err(DispatchError::Token(TokenError::Frozen)),
+            Some(core) if core.members_only_transfers => {
+                ensure!(
+                    CoreMembers::<T>::contains_key(core_id, to)
+                        || TransferWhitelist::<T>::contains_key(core_id, to),
+                    Error::<T>::RecipientNotAllowed
+                );
+
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks if `amount` of the voting asset can be minted without exceeding the core's max supply.
+    pub fn ensure_within_max_supply(core_id: T::CoreId, amount: BalanceOf<T>) -> DispatchResult {
+        if let Some(max) = CoreStorage::<T>::get(core_id).and_then(|c| c.max_supply) {
+            let supply = T::AssetsProvider::total_issuance(core_id)
+                .checked_add(&amount)
+                .ok_or(ArithmeticError::Overflow)?;
+
+            ensure!(supply <= max, Error::<T>::MaxSupplyExceeded);
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,151 @@
+//! Member Invitations.
+//!
+//! ## Overview
+//!
+//! This module allows cores to invite new members instead of minting voting tokens to them directly,
+//! so that no account becomes a member of a core without its consent.
+//!
+//! ### Core functionalities:
+//! - `inner_invite_member`: The core proposes minting an amount of its voting token to a target account. An expired
+//!   invitation to the same account is replaced, emitting `InvitationExpired` as if it had been cleared.
+//! - `inner_accept_invitation`: The target accepts the invitation before it expires, having the tokens minted.
+//! - `inner_decline_invitation`: The target declines the invitation, removing it from storage.
+//! - `inner_clear_expired_invitation`: Anyone can remove an invitation that has expired.
+
+use super::pallet::*;
+use crate::origin::{ensure_multisig, INV4Origin};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, traits::fungibles::Mutate};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
+
+/// Details of an invitation to become a member of a core.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct Invitation<Balance, BlockNumber> {
+    /// Amount of voting tokens to be minted when the invitation is accepted.
+    pub amount: Balance,
+    /// Last block in which the invitation can be accepted.
+    pub expires_at: BlockNumber,
+}
+
+pub type InvitationOf<T> = Invitation<BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+{
+    /// Inner function for the invite_member call.
+    pub(crate) fn inner_invite_member(
+        origin: OriginFor<T>,
+        amount: BalanceOf<T>,
+        target: T::AccountId,
+    ) -> DispatchResult {
+        // Grab the core id from the origin
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        let now = frame_system::Pallet::<T>::block_number();
+
+        Invitations::<T>::try_mutate(core_id, &target, |maybe_invitation| -> DispatchResult {
+            // Only allow replacing an existing invitation once it has expired
+            if let Some(invitation) = maybe_invitation {
+                ensure!(invitation.expires_at < now, Error::<T>::AlreadyInvited);
+
+                Self::deposit_event(Event::InvitationExpired {
+                    core_id,
+                    target: target.clone(),
+                });
+            }
+
+            let expires_at = now.saturating_add(T::InvitationPeriod::get());
+
+            *maybe_invitation = Some(Invitation { amount, expires_at });
+
+            Self::deposit_event(Event::MemberInvited {
+                core_id,
+                target: target.clone(),
+                amount,
+                expires_at,
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Inner function for the accept_invitation call.
+    pub(crate) fn inner_accept_invitation(
+        origin: OriginFor<T>,
+        core_id: T::CoreId,
+    ) -> DispatchResult {
+        let target = ensure_signed(origin)?;
+
+        let invitation =
+            Invitations::<T>::get(core_id, &target).ok_or(Error::<T>::InvitationNotFound)?;
+
+        ensure!(
+            frame_system::Pallet::<T>::block_number() <= invitation.expires_at,
+            Error::<T>::InvitationExpired
+        );
+
+        Invitations::<T>::remove(core_id, &target);
+
+        // Make sure the core's max supply is respected.
+        Self::ensure_within_max_supply(core_id, invitation.amount)?;
+
+        // Mint the core's voting token to the new member.
+        T::AssetsProvider::mint_into(core_id, &target, invitation.amount)?;
+
+        Self::deposit_event(Event::InvitationAccepted {
+            core_id,
+            target: target.clone(),
+            amount: invitation.amount,
+        });
+
+        Self::deposit_event(Event::Minted {
+            core_id,
+            target,
+            amount: invitation.amount,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the decline_invitation call.
+    pub(crate) fn inner_decline_invitation(
+        origin: OriginFor<T>,
+        core_id: T::CoreId,
+    ) -> DispatchResult {
+        let target = ensure_signed(origin)?;
+
+        Invitations::<T>::take(core_id, &target).ok_or(Error::<T>::InvitationNotFound)?;
+
+        Self::deposit_event(Event::InvitationDeclined { core_id, target });
+
+        Ok(())
+    }
+
+    /// Inner function for the clear_expired_invitation call.
+    pub(crate) fn inner_clear_expired_invitation(
+        origin: OriginFor<T>,
+        core_id: T::CoreId,
+        target: T::AccountId,
+    ) -> DispatchResult {
+        ensure_signed(origin)?;
+
+        let invitation =
+            Invitations::<T>::get(core_id, &target).ok_or(Error::<T>::InvitationNotFound)?;
+
+        ensure!(
+            invitation.expires_at < frame_system::Pallet::<T>::block_number(),
+            Error::<T>::InvitationNotExpired
+        );
+
+        Invitations::<T>::remove(core_id, &target);
+
+        Self::deposit_event(Event::InvitationExpired { core_id, target });
+
+        Ok(())
+    }
+}
@@ -16,10 +16,36 @@
 //! - `token_mint` - Mint the core's voting token to a target (called by a core origin)
 //! - `token_burn` - Burn the core's voting token from a target (called by a core origin)
 //! - `operate_multisig` - Create a new multisig proposal, auto-executing if caller passes execution threshold requirements
+//! - `operate_multisig_batch` - Create a new multisig proposal dispatching a batch of calls atomically
 //! - `vote_multisig` - Vote on an existing multisig proposal, auto-executing if caller puts vote tally past execution threshold requirements
+//! - `execute_multisig` - Execute a passed multisig proposal once its dependencies are done
 //! - `withdraw_vote_multisig` - Remove caller's vote from an existing multisig proposal
+//! - `clear_multisig_votes` - Clear the votes a proposal left behind when it left the voting stage
 //! - `cancel_multisig_proposal` - Cancel an existing multisig proposal (called by a core origin)
-//! - `set_parameters` - Change core parameters incl. voting thresholds and token freeze state (called by a core origin)
+//! - `amend_multisig_metadata` - Update the metadata of a pending multisig proposal (called by the proposal's original caller)
+//! - `set_parameters` - Change core parameters incl. voting thresholds, minimum voters and token freeze state (called by a core origin)
+//! - `set_transfer_whitelist` - Add or remove an account from the core's whitelist of voting token recipients (called by a core origin)
+//! - `invite_member` - Invite a target to become a member, minting the core's voting token once accepted (called by a core origin)
+//! - `accept_invitation` - Accept an invitation to become a member of a core, minting the invited amount to the caller
+//! - `decline_invitation` - Decline an invitation to become a member of a core
+//! - `clear_expired_invitation` - Remove an invitation that was not accepted in time
+//! - `open_token_sale` - Open a bounded sale of the core's voting token (called by a core origin)
+//! - `close_token_sale` - Close the core's voting token sale (called by a core origin)
+//! - `buy_tokens` - Buy voting tokens from a core's ongoing sale
+//! - `token_mint_vested` - Mint the core's voting token to a target under a vesting schedule (called by a core origin)
+//! - `vest` - Release the caller's voting tokens unlocked by their vesting schedules
+//! - `set_max_supply` - Change the core's voting token supply cap, requiring the core's supermajority (called by a core origin)
+//! - `set_proposal_limits` - Set or remove the limits on the proposals members can create (called by a core origin)
+//! - `as_derivative` - Dispatch a call from one of the core's derivative sub-accounts (called by a core origin)
+//! - `set_remote_member` - Map a location in another chain to a member account, or remove the mapping (called by a core origin)
+//! - `vote_multisig_remote` - Vote on an existing multisig proposal from another chain, as the member mapped to the origin's location
+//! - `register_handle` - Register a unique human-readable handle for the core, reserving a deposit (called by a core origin)
+//! - `transfer_handle` - Transfer the core's handle to another core along with its deposit (called by a core origin)
+//! - `release_handle` - Release the core's handle, returning its deposit (called by a core origin)
+//!
+//! ### Genesis
+//!
+//! Cores can be created at genesis through [`GenesisConfig`], e.g. for dev chains and test networks.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -34,17 +60,31 @@ mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-//pub mod migrations;
-
 pub mod account_derivation;
+pub mod archive;
+pub mod asset_hooks;
+pub mod dependencies;
+pub mod derivatives;
 mod dispatch;
+pub mod dry_run;
 pub mod fee_handling;
+pub mod handles;
 pub mod inv4_core;
+pub mod invitations;
 mod lookup;
+pub mod migrations;
 pub mod multisig;
 pub mod origin;
+pub mod proposal_limits;
+pub mod queries;
+pub mod remote_voting;
+pub mod token_sale;
+#[cfg(any(feature = "try-runtime", test))]
+mod try_state;
+pub mod vesting;
 pub mod voting;
 pub mod weights;
+pub mod xcm_conversion;
 
 pub use account_derivation::CoreAccountDerivation;
 use fee_handling::FeeAsset;
@@ -56,7 +96,8 @@ pub mod pallet {
     use core::iter::Sum;
 
     use crate::{
-        fee_handling::MultisigFeeHandler,
+        fee_handling::{FeeAssetBalanceOf, MultisigFeeHandler},
+        multisig::MAX_VOTES_CLEARED,
         voting::{Tally, VoteRecord},
     };
 
@@ -67,7 +108,7 @@ pub mod pallet {
         pallet_prelude::*,
         traits::{
             fungibles,
-            fungibles::{Balanced, Inspect},
+            fungibles::{Balanced, Inspect, Mutate},
             Currency, Get, GetCallMetadata, ReservableCurrency,
         },
         transactional,
@@ -75,13 +116,16 @@ pub mod pallet {
         Parameter,
     };
     use frame_system::{pallet_prelude::*, RawOrigin};
-    use primitives::CoreInfo;
+    use primitives::{CoreInfo, GenesisCore, MinimumVoters};
     use scale_info::prelude::fmt::Display;
     use sp_runtime::{
-        traits::{AtLeast32BitUnsigned, Dispatchable, Member},
+        traits::{
+            AtLeast32BitUnsigned, CheckedAdd, Dispatchable, Member, One, TrailingZeroInput, Zero,
+        },
         Perbill,
     };
     use sp_std::{boxed::Box, convert::TryInto, vec::Vec};
+    use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
     pub use super::{inv4_core, multisig};
 
@@ -90,11 +134,21 @@ pub mod pallet {
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-    pub type CoreInfoOf<T> =
-        CoreInfo<<T as frame_system::Config>::AccountId, inv4_core::CoreMetadataOf<T>>;
+    pub type CoreInfoOf<T> = CoreInfo<
+        <T as frame_system::Config>::AccountId,
+        inv4_core::CoreMetadataOf<T>,
+        BalanceOf<T>,
+    >;
 
     pub type CallOf<T> = <T as Config>::RuntimeCall;
 
+    /// Core created at genesis through [`GenesisConfig`].
+    pub type GenesisCoreOf<T> = GenesisCore<
+        inv4_core::CoreMetadataOf<T>,
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+    >;
+
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_balances::Config {
         /// Runtime event type
@@ -124,13 +178,25 @@ pub mod pallet {
             + GetCallMetadata
             + FullCodec;
 
-        /// The maximum numbers of caller accounts on a single multisig proposal
+        /// The maximum length of the metadata of multisig proposals
         #[pallet::constant]
-        type MaxCallers: Get<u32>;
+        type MaxMetadata: Get<u32>;
 
-        /// The maximum length of the core metadata and the metadata of multisig proposals
+        /// The maximum length of a core's name
         #[pallet::constant]
-        type MaxMetadata: Get<u32>;
+        type MaxNameLength: Get<u32>;
+
+        /// The maximum length of a core's description
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+
+        /// The maximum length of a core's image URL
+        #[pallet::constant]
+        type MaxImageUrlLength: Get<u32>;
+
+        /// The maximum length of a core's website URL
+        #[pallet::constant]
+        type MaxWebsiteLength: Get<u32>;
 
         /// The outer `Origin` type.
         type RuntimeOrigin: From<Origin<Self>>
@@ -177,10 +243,44 @@ pub mod pallet {
 
         /// Byte to fee conversion provider, from pallet_transaction_payment.
         type LengthToFee: WeightToFee<Balance = BalanceOf<Self>>;
+
+        /// Number of blocks a member invitation can be accepted for
+        #[pallet::constant]
+        type InvitationPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of vesting schedules a member can have in a single core
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+
+        /// The maximum number of proposals a multisig proposal can depend on
+        #[pallet::constant]
+        type MaxDependencies: Get<u32>;
+
+        /// The maximum number of calls in a batch proposal
+        #[pallet::constant]
+        type MaxBatchCalls: Get<u32>;
+
+        /// The number of resolved proposals kept in the archive of each core
+        #[pallet::constant]
+        type MaxArchivedProposals: Get<u32>;
+
+        /// Origin of votes sent from other chains, returning the location they came from
+        type RemoteVoteOrigin: EnsureOrigin<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            Success = MultiLocation,
+        >;
+
+        /// The maximum length of a core's handle
+        #[pallet::constant]
+        type MaxHandleLength: Get<u32>;
+
+        /// Deposit reserved from the core account when registering a handle
+        #[pallet::constant]
+        type HandleDeposit: Get<BalanceOf<Self>>;
     }
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     /// The custom core origin.
     #[pallet::origin]
@@ -219,14 +319,285 @@ pub mod pallet {
         crate::multisig::MultisigOperationOf<T>,
     >;
 
+    /// Votes of each member on multisig proposals.
+    /// Votes are cleared when a proposal leaves the voting stage, up to `MAX_VOTES_CLEARED` of them, the rest are
+    /// kept until they're cleared with `clear_multisig_votes`.
+    ///
+    /// Key: (Core ID, call hash, account)
+    #[pallet::storage]
+    #[pallet::getter(fn multisig_votes)]
+    pub type MultisigVotes<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::CoreId>,
+            NMapKey<Blake2_128Concat, T::Hash>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        VoteRecord<T>,
+    >;
+
+    /// Resolved proposals of each core, kept in a ring buffer of `MaxArchivedProposals` slots.
+    ///
+    /// Key: (Core ID, slot)
+    #[pallet::storage]
+    pub type ProposalArchive<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CoreId,
+        Twox64Concat,
+        u32,
+        crate::archive::ArchivedProposalOf<T>,
+    >;
+
+    /// Number of proposals ever archived for each core, used to find the next slot of the archive.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_archive_count)]
+    pub type ProposalArchiveCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CoreId, u64, ValueQuery>;
+
+    /// Number of pending proposals depending on each proposal.
+    ///
+    /// Key: (Core ID, call hash)
+    #[pallet::storage]
+    #[pallet::getter(fn dependents)]
+    pub type Dependents<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CoreId,
+        Blake2_128Concat,
+        T::Hash,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Whether proposals that other pending proposals depend on executed successfully.
+    /// Results are dropped once no pending proposal depends on them anymore.
+    ///
+    /// Key: (Core ID, call hash)
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_results)]
+    pub type ProposalResults<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::CoreId, Blake2_128Concat, T::Hash, bool>;
+
     /// Stores a list of members for each Core.
-    /// This storage should be always handled by the runtime and mutated by CoreAssets hooks.
+    /// This storage should be always handled by the runtime and mutated by CoreAssets hooks, see [`crate::asset_hooks`].
     // We make this a StorageDoubleMap so we don't have to bound the list.
     #[pallet::storage]
     #[pallet::getter(fn core_members)]
     pub type CoreMembers<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, T::CoreId, Blake2_128Concat, T::AccountId, ()>;
 
+    /// Reverse index of [`CoreMembers`], listing the cores each account is a member of.
+    /// Kept in sync with [`CoreMembers`] by the same CoreAssets hooks.
+    #[pallet::storage]
+    #[pallet::getter(fn member_cores)]
+    pub type MemberCores<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::CoreId, ()>;
+
+    /// Number of members of each Core, kept in sync with [`CoreMembers`].
+    #[pallet::storage]
+    #[pallet::getter(fn core_member_count)]
+    pub type CoreMemberCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CoreId, u32, ValueQuery>;
+
+    /// Limits on the proposals members of a core can create.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_limits)]
+    pub type CoreProposalLimits<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CoreId, crate::proposal_limits::ProposalLimitsOf<T>>;
+
+    /// Number of proposals each member currently has in the voting stage.
+    ///
+    /// Key: (Core ID, proposer account id)
+    #[pallet::storage]
+    #[pallet::getter(fn open_proposals)]
+    pub type OpenProposals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CoreId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Block in which each member last created a proposal.
+    ///
+    /// Removed once the member has no open proposals and the cooldown is over, or when the member leaves the core.
+    ///
+    /// Key: (Core ID, proposer account id)
+    #[pallet::storage]
+    #[pallet::getter(fn last_proposal)]
+    pub type LastProposal<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CoreId,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+    >;
+
+    /// Deposits reserved from the proposers of proposals in the voting stage.
+    ///
+    /// Key: (Core ID, call hash)
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_deposits)]
+    pub type ProposalDeposits<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::CoreId, Blake2_128Concat, T::Hash, BalanceOf<T>>;
+
+    /// Accounts allowed to receive a core's voting token when the core only allows transfers between members.
+    ///
+    /// Key: (Core ID, account id)
+    #[pallet::storage]
+    #[pallet::getter(fn transfer_whitelist)]
+    pub type TransferWhitelist<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::CoreId, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Pending invitations to become a member of a core.
+    ///
+    /// Key: (Core ID, invited account id)
+    #[pallet::storage]
+    #[pallet::getter(fn invitations)]
+    pub type Invitations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CoreId,
+        Blake2_128Concat,
+        T::AccountId,
+        crate::invitations::InvitationOf<T>,
+    >;
+
+    /// Voting token sale of each core.
+    #[pallet::storage]
+    #[pallet::getter(fn token_sales)]
+    pub type TokenSales<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CoreId, crate::token_sale::TokenSaleOf<T>>;
+
+    /// Vesting schedules of the voting tokens minted to a member.
+    ///
+    /// Key: (Core ID, member account id)
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedules)]
+    pub type VestingSchedules<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CoreId,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<crate::vesting::VestingOf<T>, T::MaxVestingSchedules>,
+        ValueQuery,
+    >;
+
+    /// Member accounts voting on behalf of locations in other chains.
+    ///
+    /// Key: (Core ID, remote location)
+    #[pallet::storage]
+    #[pallet::getter(fn remote_members)]
+    pub type RemoteMembers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CoreId,
+        Blake2_128Concat,
+        MultiLocation,
+        T::AccountId,
+    >;
+
+    /// Cores owning each handle.
+    ///
+    /// Key: handle
+    #[pallet::storage]
+    #[pallet::getter(fn handle_owners)]
+    pub type HandleOwners<T: Config> =
+        StorageMap<_, Blake2_128Concat, crate::handles::CoreHandleOf<T>, T::CoreId>;
+
+    /// Handle registered by each core and its deposit.
+    ///
+    /// Key: Core ID
+    #[pallet::storage]
+    #[pallet::getter(fn core_handles)]
+    pub type CoreHandles<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CoreId, crate::handles::HandleRecordOf<T>>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Cores to create, with ids assigned in order starting from the current `NextCoreId`.
+        pub cores: Vec<GenesisCoreOf<T>>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (index, core) in self.cores.iter().enumerate() {
+                assert!(
+                    !core.members.is_empty(),
+                    "Genesis core at index {} needs at least one member",
+                    index
+                );
+
+                if let Some(max_supply) = core.max_supply {
+                    let supply = core
+                        .members
+                        .iter()
+                        .try_fold(BalanceOf::<T>::zero(), |supply, (_, balance)| {
+                            supply.checked_add(balance)
+                        });
+
+                    assert!(
+                        supply.is_some_and(|supply| supply <= max_supply),
+                        "Genesis core at index {} exceeds its max supply",
+                        index
+                    );
+                }
+
+                let core_id = NextCoreId::<T>::get();
+                NextCoreId::<T>::put(
+                    core_id
+                        .checked_add(&One::one())
+                        .expect("Genesis cores exceed the available core ids"),
+                );
+
+                // Same account as `derive_core_account`, which needs `T::AccountId: From<[u8; 32]>`
+                let core_account = T::AccountId::decode(&mut TrailingZeroInput::new(
+                    &crate::account_derivation::core_account_hash(T::ParaId::get(), core_id.into()),
+                ))
+                .expect("infinite length input; no invalid inputs for type; qed");
+
+                CoreStorage::<T>::insert(
+                    core_id,
+                    CoreInfo {
+                        account: core_account.clone(),
+                        metadata: core.metadata.clone(),
+                        minimum_support: core.minimum_support,
+                        required_approval: core.required_approval,
+                        minimum_voters: core.minimum_voters,
+                        frozen_tokens: core.frozen_tokens,
+                        members_only_transfers: core.members_only_transfers,
+                        max_supply: core.max_supply,
+                        max_supply_supermajority: Perbill::one(),
+                    },
+                );
+                CoreByAccount::<T>::insert(core_account, core_id);
+
+                for (member, balance) in &core.members {
+                    T::AssetsProvider::mint_into(core_id, member, *balance)
+                        .expect("Failed minting the voting token of a genesis core");
+
+                    // Not left to the token hooks, so members are set even if the runtime doesn't use them
+                    Pallet::<T>::add_member(&core_id, member);
+                }
+            }
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -234,7 +605,7 @@ pub mod pallet {
         CoreCreated {
             core_account: T::AccountId,
             core_id: T::CoreId,
-            metadata: Vec<u8>,
+            metadata: inv4_core::CoreMetadataOf<T>,
             minimum_support: Perbill,
             required_approval: Perbill,
         },
@@ -242,10 +613,12 @@ pub mod pallet {
         /// A core had parameters changed
         ParametersSet {
             core_id: T::CoreId,
-            metadata: Option<Vec<u8>>,
+            metadata: Option<inv4_core::CoreMetadataOf<T>>,
             minimum_support: Option<Perbill>,
             required_approval: Option<Perbill>,
             frozen_tokens: Option<bool>,
+            members_only_transfers: Option<bool>,
+            minimum_voters: Option<Option<MinimumVoters>>,
         },
 
         /// A core's voting token was minted
@@ -290,13 +663,16 @@ pub mod pallet {
             call_hash: T::Hash,
         },
 
-        /// A multisig proposal passed and it's call was executed
+        /// A multisig proposal passed and it's calls were executed
+        /// `result` is the result of the whole proposal, `failed_call` the index of the call that failed, reverting
+        /// the effects of all of them
         MultisigExecuted {
             core_id: T::CoreId,
             executor_account: T::AccountId,
             voter: T::AccountId,
             call_hash: T::Hash,
-            call: CallOf<T>,
+            calls: Vec<CallOf<T>>,
+            failed_call: Option<u32>,
             result: DispatchResult,
         },
 
@@ -305,6 +681,169 @@ pub mod pallet {
             core_id: T::CoreId,
             call_hash: T::Hash,
         },
+
+        /// An account was added to or removed from a core's transfer whitelist
+        TransferWhitelistSet {
+            core_id: T::CoreId,
+            account: T::AccountId,
+            whitelisted: bool,
+        },
+
+        /// An account was invited to become a member of a core
+        MemberInvited {
+            core_id: T::CoreId,
+            target: T::AccountId,
+            amount: BalanceOf<T>,
+            expires_at: BlockNumberFor<T>,
+        },
+
+        /// An invitation was accepted and the core's voting token was minted to the new member
+        InvitationAccepted {
+            core_id: T::CoreId,
+            target: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+
+        /// An invitation was declined by the invited account
+        InvitationDeclined {
+            core_id: T::CoreId,
+            target: T::AccountId,
+        },
+
+        /// An invitation expired without being accepted and was removed
+        InvitationExpired {
+            core_id: T::CoreId,
+            target: T::AccountId,
+        },
+
+        /// A core opened a sale of its voting token
+        TokenSaleOpened {
+            core_id: T::CoreId,
+            price: FeeAssetBalanceOf<T>,
+            cap: BalanceOf<T>,
+            start: BlockNumberFor<T>,
+            end: BlockNumberFor<T>,
+        },
+
+        /// A core's voting token sale was closed
+        TokenSaleClosed {
+            core_id: T::CoreId,
+            sold: BalanceOf<T>,
+        },
+
+        /// Voting tokens were bought from a core's sale
+        TokensBought {
+            core_id: T::CoreId,
+            buyer: T::AccountId,
+            amount: BalanceOf<T>,
+            cost: FeeAssetBalanceOf<T>,
+        },
+
+        /// A core's voting token was minted under a vesting schedule
+        VestingScheduleAdded {
+            core_id: T::CoreId,
+            target: T::AccountId,
+            vesting: crate::vesting::VestingOf<T>,
+        },
+
+        /// Voting tokens unlocked by vesting schedules were released
+        Vested {
+            core_id: T::CoreId,
+            target: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+
+        /// A core's voting token supply cap was changed
+        MaxSupplySet {
+            core_id: T::CoreId,
+            max_supply: Option<BalanceOf<T>>,
+            supermajority: Perbill,
+        },
+
+        /// A core's proposal limits were set or removed
+        ProposalLimitsSet {
+            core_id: T::CoreId,
+            limits: Option<crate::proposal_limits::ProposalLimitsOf<T>>,
+        },
+
+        /// The deposit of a canceled proposal was slashed to the core account
+        ProposalDepositSlashed {
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            proposer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+
+        /// The metadata of a multisig proposal was amended by its original caller
+        MultisigMetadataAmended {
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            metadata: Option<Vec<u8>>,
+        },
+
+        /// A multisig proposal passed but its execution is waiting on its dependencies
+        MultisigExecutionDeferred {
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+        },
+
+        /// A multisig proposal was canceled because one of its dependencies failed
+        MultisigDependencyFailed {
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            dependency: T::Hash,
+        },
+
+        /// Votes of a proposal that left the voting stage were cleared
+        /// `complete` is false if there are still votes left to be cleared
+        MultisigVotesCleared {
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            cleared: u32,
+            complete: bool,
+        },
+
+        /// A call was dispatched from one of the core's derivative sub-accounts, with the call's result
+        DerivativeDispatched {
+            core_id: T::CoreId,
+            index: u16,
+            account: T::AccountId,
+            result: DispatchResult,
+        },
+
+        /// A location in another chain was mapped to a member account, or the mapping was removed
+        RemoteMemberSet {
+            core_id: T::CoreId,
+            location: MultiLocation,
+            member: Option<T::AccountId>,
+        },
+
+        /// A core registered a handle
+        HandleRegistered {
+            core_id: T::CoreId,
+            handle: crate::handles::CoreHandleOf<T>,
+        },
+
+        /// A core transferred its handle to another core
+        HandleTransferred {
+            handle: crate::handles::CoreHandleOf<T>,
+            from: T::CoreId,
+            to: T::CoreId,
+        },
+
+        /// A core released its handle
+        HandleReleased {
+            core_id: T::CoreId,
+            handle: crate::handles::CoreHandleOf<T>,
+        },
+
+        /// A multisig proposal failed or was canceled while other proposals depend on it
+        /// The `dependents` are canceled the next time they're voted on or executed
+        MultisigDependentsFailed {
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            dependents: u32,
+        },
     }
 
     /// Errors for INV4 pallet
@@ -318,7 +857,8 @@ pub mod pallet {
         NoPermission,
         /// Maximum metadata length exceeded
         MaxMetadataExceeded,
-        /// Maximum amount of callers exceeded
+        /// Deprecated, no longer returned since votes are stored in `MultisigVotes`
+        /// Kept so the indexes of the errors after it don't change
         MaxCallersExceeded,
         /// Multisig call not found
         MultisigCallNotFound,
@@ -336,6 +876,76 @@ pub mod pallet {
         CallFeePaymentFailed,
         /// Call is too long
         MaxCallLengthExceeded,
+        /// The core only allows transfers of its voting token to members or whitelisted accounts
+        RecipientNotAllowed,
+        /// The target already has a pending invitation to this core
+        AlreadyInvited,
+        /// Invitation not found
+        InvitationNotFound,
+        /// The invitation can no longer be accepted
+        InvitationExpired,
+        /// The invitation can still be accepted
+        InvitationNotExpired,
+        /// The sale must end after it starts and can't end in the past
+        InvalidTokenSalePeriod,
+        /// The core already has an ongoing token sale
+        TokenSaleAlreadyExists,
+        /// Token sale not found
+        TokenSaleNotFound,
+        /// The token sale hasn't started yet or has already ended
+        TokenSaleNotActive,
+        /// Buying this amount would exceed the token sale's cap
+        TokenSaleCapExceeded,
+        /// Failed to deposit the token sale payment to the core account
+        TokenSalePaymentFailed,
+        /// Vesting schedules must vest a non-zero amount and linear schedules must end after they start
+        InvalidVestingSchedule,
+        /// Maximum amount of vesting schedules exceeded
+        MaxVestingSchedulesExceeded,
+        /// The account has no vesting schedules in this core
+        NoVestingSchedule,
+        /// The amount exceeds the balance not locked by vesting schedules
+        InsufficientUnlockedBalance,
+        /// The voting token supply would exceed the core's max supply
+        MaxSupplyExceeded,
+        /// The proposal was not approved by the core's supermajority
+        SupermajorityNotReached,
+        /// The caller's voting token balance is below the core's minimum to create proposals
+        BalanceTooLowToPropose,
+        /// The caller has too many proposals in the voting stage
+        TooManyOpenProposals,
+        /// The caller has to wait for the core's proposal cooldown to end
+        ProposalCooldown,
+        /// Only the original caller of a multisig proposal can amend it
+        NotOriginalCaller,
+        /// A dependency of the proposal is neither pending nor resolved
+        DependencyNotFound,
+        /// A dependency of the proposal failed or was canceled
+        DependencyFailed,
+        /// The dependencies of the proposal didn't execute yet
+        DependenciesPending,
+        /// The proposal doesn't meet the core's thresholds
+        MultisigNotApproved,
+        /// Batch proposals must have at least one call
+        EmptyBatch,
+        /// The weight of the proposal's calls exceeds the given max call weight
+        MaxCallWeightExceeded,
+        /// Votes of a previous proposal with the same call hash must be cleared first
+        VotesNotCleared,
+        /// Votes can't be cleared while the proposal is in the voting stage
+        MultisigStillOngoing,
+        /// The XCM version of the location is not supported
+        UnsupportedXcmVersion,
+        /// The origin's location is not mapped to a member of the core
+        RemoteMemberNotFound,
+        /// Handles can only contain lowercase ASCII letters, digits and dashes
+        InvalidHandle,
+        /// The handle is already registered by another core
+        HandleAlreadyTaken,
+        /// The core already has a handle
+        CoreAlreadyHasHandle,
+        /// The core has no handle
+        HandleNotFound,
     }
 
     /// Dispatch functions
@@ -350,16 +960,16 @@ pub mod pallet {
     <T as frame_system::Config>::AccountId: From<[u8; 32]>,
     {
         /// Create a new core
-        /// - `metadata`: Arbitrary byte vec to be attached to the core info
+        /// - `metadata`: Name, description, image, website and document hash of the core
         /// - `minimum_support`: Minimum amount of positive votes out of total token supply required to approve a proposal
         /// - `required_approval`: Minimum amount of positive votes out of current positive + negative votes required to approve a proposal
         /// - `creation_fee_asset`: Token to be used to pay the core creation fee
         #[pallet::call_index(0)]
         #[transactional]
-        #[pallet::weight(<T as Config>::WeightInfo::create_core(metadata.len() as u32))]
+        #[pallet::weight(<T as Config>::WeightInfo::create_core(metadata.encoded_size() as u32))]
         pub fn create_core(
             owner: OriginFor<T>,
-            metadata: BoundedVec<u8, T::MaxMetadata>,
+            metadata: inv4_core::CoreMetadataOf<T>,
             minimum_support: Perbill,
             required_approval: Perbill,
             creation_fee_asset: FeeAsset,
@@ -407,16 +1017,18 @@ pub mod pallet {
         /// Create a new multisig proposal, auto-executing if caller passes execution threshold requirements
         /// Fees are calculated using the length of the metadata and the call
         /// The proposed call's weight is used internally to charge the multisig instead of the user proposing the call
+        /// The core's proposal limits only apply if the call goes into voting, auto-executed calls are exempt
         /// - `core_id`: Id of the core to propose the call in
         /// - `metadata`: Arbitrary byte vec to be attached to the proposal
         /// - `fee_asset`: Token to be used by the multisig to pay for call fees
         /// - `call`: The actual call to be proposed
+        /// - `depends_on`: Call hashes of the proposals that must execute successfully before this one
         #[pallet::call_index(3)]
         #[pallet::weight(
             <T as Config>::WeightInfo::operate_multisig(
                 metadata.clone().map(|m| m.len()).unwrap_or(0) as u32,
                 call.using_encoded(|c| c.len() as u32)
-            )
+            ).saturating_add(call.get_dispatch_info().weight)
         )]
         pub fn operate_multisig(
             caller: OriginFor<T>,
@@ -424,23 +1036,37 @@ pub mod pallet {
             metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
             fee_asset: FeeAsset,
             call: Box<<T as pallet::Config>::RuntimeCall>,
+            depends_on: crate::multisig::DependenciesOf<T>,
         ) -> DispatchResultWithPostInfo {
-            Pallet::<T>::inner_operate_multisig(caller, core_id, metadata, fee_asset, call)
+            Pallet::<T>::inner_operate_multisig(
+                caller,
+                core_id,
+                metadata,
+                fee_asset,
+                sp_std::vec![*call],
+                depends_on,
+            )
         }
 
         /// Vote on an existing multisig proposal, auto-executing if caller puts vote tally past execution threshold requirements
         /// - `core_id`: Id of the core where the proposal is
         /// - `call_hash`: Hash of the call identifying the proposal
         /// - `aye`: Wheter or not to vote positively
+        /// - `max_call_weight`: Upper bound for the weight of the proposal's calls, in case the vote executes it
         #[pallet::call_index(4)]
-        #[pallet::weight(<T as Config>::WeightInfo::vote_multisig())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::vote_multisig()
+                .saturating_add(<T as Config>::WeightInfo::clear_multisig_votes(MAX_VOTES_CLEARED))
+                .saturating_add(*max_call_weight)
+        )]
         pub fn vote_multisig(
             caller: OriginFor<T>,
             core_id: T::CoreId,
             call_hash: T::Hash,
             aye: bool,
+            max_call_weight: Weight,
         ) -> DispatchResultWithPostInfo {
-            Pallet::<T>::inner_vote_multisig(caller, core_id, call_hash, aye)
+            Pallet::<T>::inner_vote_multisig(caller, core_id, call_hash, aye, max_call_weight)
         }
 
         /// Remove caller's vote from an existing multisig proposal
@@ -459,7 +1085,10 @@ pub mod pallet {
         /// Cancel an existing multisig proposal (called by a core origin)
         /// - `call_hash`: Hash of the call identifying the proposal
         #[pallet::call_index(6)]
-        #[pallet::weight(<T as Config>::WeightInfo::cancel_multisig_proposal())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::cancel_multisig_proposal()
+                .saturating_add(<T as Config>::WeightInfo::clear_multisig_votes(MAX_VOTES_CLEARED))
+        )]
         pub fn cancel_multisig_proposal(
             caller: OriginFor<T>,
             call_hash: T::Hash,
@@ -468,22 +1097,335 @@ pub mod pallet {
         }
 
         /// Change core parameters incl. voting thresholds and token freeze state (called by a core origin)
-        /// - `metadata`: Arbitrary byte vec to be attached to the core info
+        /// - `metadata`: Name, description, image, website and document hash of the core
         /// - `minimum_support`: Minimum amount of positive votes out of total token supply required to approve a proposal
         /// - `required_approval`: Minimum amount of positive votes out of current positive + negative votes required to approve a proposal
         /// - `frozen_tokens`: Wheter or not the core's voting token should be transferable by the holders
+        /// - `members_only_transfers`: Whether or not the core's voting token should only be transferable to members or whitelisted accounts
+        /// - `minimum_voters`: Minimum amount of members that must vote on a proposal, `Some(None)` removes the requirement
         #[pallet::call_index(9)]
         #[pallet::weight(<T as Config>::WeightInfo::set_parameters(
-            metadata.clone().map(|m| m.len()).unwrap_or(0) as u32
+            metadata.as_ref().map(|m| m.encoded_size()).unwrap_or(0) as u32
         ))]
         pub fn set_parameters(
             origin: OriginFor<T>,
-            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+            metadata: Option<inv4_core::CoreMetadataOf<T>>,
             minimum_support: Option<Perbill>,
             required_approval: Option<Perbill>,
             frozen_tokens: Option<bool>,
+            members_only_transfers: Option<bool>,
+            minimum_voters: Option<Option<MinimumVoters>>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_parameters(origin, metadata, minimum_support, required_approval, frozen_tokens, members_only_transfers, minimum_voters)
+        }
+
+        /// Add or remove an account from the core's whitelist of voting token recipients (called by a core origin)
+        /// - `account`: Account to be added to or removed from the whitelist
+        /// - `whitelisted`: Whether the account should be allowed to receive the core's voting token
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_transfer_whitelist())]
+        pub fn set_transfer_whitelist(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            whitelisted: bool,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_transfer_whitelist(origin, account, whitelisted)
+        }
+
+        /// Invite a target to become a member, minting the core's voting token once accepted (called by a core origin)
+        /// - `amount`: Balance amount to be minted when the invitation is accepted
+        /// - `target`: Account being invited
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::invite_member())]
+        pub fn invite_member(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+            target: T::AccountId,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_invite_member(origin, amount, target)
+        }
+
+        /// Accept an invitation to become a member of a core, minting the invited amount to the caller
+        /// - `core_id`: Id of the core that sent the invitation
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_invitation())]
+        pub fn accept_invitation(origin: OriginFor<T>, core_id: T::CoreId) -> DispatchResult {
+            Pallet::<T>::inner_accept_invitation(origin, core_id)
+        }
+
+        /// Decline an invitation to become a member of a core
+        /// - `core_id`: Id of the core that sent the invitation
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::decline_invitation())]
+        pub fn decline_invitation(origin: OriginFor<T>, core_id: T::CoreId) -> DispatchResult {
+            Pallet::<T>::inner_decline_invitation(origin, core_id)
+        }
+
+        /// Remove an invitation that was not accepted in time
+        /// - `core_id`: Id of the core that sent the invitation
+        /// - `target`: Account that was invited
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::clear_expired_invitation())]
+        pub fn clear_expired_invitation(
+            origin: OriginFor<T>,
+            core_id: T::CoreId,
+            target: T::AccountId,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_clear_expired_invitation(origin, core_id, target)
+        }
+
+        /// Open a bounded sale of the core's voting token (called by a core origin)
+        /// - `price`: Price of a single voting token in the asset to be accepted as payment
+        /// - `cap`: Maximum amount of voting tokens to be sold
+        /// - `start`: First block in which tokens can be bought
+        /// - `end`: Last block in which tokens can be bought
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::open_token_sale())]
+        pub fn open_token_sale(
+            origin: OriginFor<T>,
+            price: FeeAssetBalanceOf<T>,
+            cap: BalanceOf<T>,
+            start: BlockNumberFor<T>,
+            end: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_open_token_sale(origin, price, cap, start, end)
+        }
+
+        /// Close the core's voting token sale (called by a core origin)
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::close_token_sale())]
+        pub fn close_token_sale(origin: OriginFor<T>) -> DispatchResult {
+            Pallet::<T>::inner_close_token_sale(origin)
+        }
+
+        /// Buy voting tokens from a core's ongoing sale
+        /// - `core_id`: Id of the core selling the tokens
+        /// - `amount`: Amount of voting tokens to buy
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::buy_tokens())]
+        pub fn buy_tokens(
+            origin: OriginFor<T>,
+            core_id: T::CoreId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_buy_tokens(origin, core_id, amount)
+        }
+
+        /// Mint the core's voting token to a target under a vesting schedule (called by a core origin)
+        /// - `amount`: Balance amount
+        /// - `target`: Account receiving the minted tokens
+        /// - `schedule`: Schedule defining how the minted tokens are unlocked
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::token_mint_vested())]
+        pub fn token_mint_vested(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+            target: T::AccountId,
+            schedule: crate::vesting::VestingSchedule<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_token_mint_vested(origin, amount, target, schedule)
+        }
+
+        /// Release the caller's voting tokens unlocked by their vesting schedules
+        /// - `core_id`: Id of the core that minted the tokens
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::vest())]
+        pub fn vest(origin: OriginFor<T>, core_id: T::CoreId) -> DispatchResult {
+            Pallet::<T>::inner_vest(origin, core_id)
+        }
+
+        /// Change the core's voting token supply cap (called by a core origin)
+        /// The proposal must be supported by at least the core's current supermajority
+        /// - `max_supply`: New supply cap, `None` removes the cap
+        /// - `supermajority`: New support required to change the supply cap, unchanged if `None`
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_max_supply())]
+        pub fn set_max_supply(
+            origin: OriginFor<T>,
+            max_supply: Option<BalanceOf<T>>,
+            supermajority: Option<Perbill>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_max_supply(origin, max_supply, supermajority)
+        }
+
+        /// Set or remove the limits on the proposals members can create (called by a core origin)
+        /// Limits only apply to proposals going into voting, calls auto-executed by their proposer are exempt
+        /// - `limits`: New proposal limits, `None` removes them
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_proposal_limits())]
+        pub fn set_proposal_limits(
+            origin: OriginFor<T>,
+            limits: Option<crate::proposal_limits::ProposalLimitsOf<T>>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_proposal_limits(origin, limits)
+        }
+
+        /// Update the metadata of a pending multisig proposal, keeping its votes
+        /// Only the original caller of the proposal can amend it, paying for any extra storage
+        /// - `core_id`: Id of the core the proposal belongs to
+        /// - `call_hash`: Hash of the proposal's call
+        /// - `metadata`: New metadata of the proposal
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::amend_multisig_metadata(
+            metadata.as_ref().map(|m| m.len()).unwrap_or(0) as u32
+        ))]
+        pub fn amend_multisig_metadata(
+            caller: OriginFor<T>,
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_amend_multisig_metadata(caller, core_id, call_hash, metadata)
+        }
+
+        /// Execute a passed multisig proposal once its dependencies executed successfully
+        /// Proposals with a failed dependency are canceled instead
+        /// - `core_id`: Id of the core where the proposal is
+        /// - `call_hash`: Hash of the call identifying the proposal
+        /// - `max_call_weight`: Upper bound for the weight of the proposal's calls
+        #[pallet::call_index(23)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::execute_multisig()
+                .saturating_add(<T as Config>::WeightInfo::clear_multisig_votes(MAX_VOTES_CLEARED))
+                .saturating_add(*max_call_weight)
+        )]
+        pub fn execute_multisig(
+            caller: OriginFor<T>,
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            max_call_weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_execute_multisig(caller, core_id, call_hash, max_call_weight)
+        }
+
+        /// Create a new multisig proposal with a batch of calls, voted on as a single proposal and dispatched atomically
+        /// If any of the calls fails, the whole batch is reverted
+        /// The core's proposal limits only apply if the batch goes into voting, auto-executed batches are exempt
+        /// - `core_id`: Id of the core
+        /// - `metadata`: Arbitrary byte array to be used for the proposal
+        /// - `fee_asset`: Asset used to pay the fees of the calls
+        /// - `calls`: The calls to be proposed
+        /// - `depends_on`: Call hashes of the proposals that must execute successfully before this one
+        #[pallet::call_index(24)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::operate_multisig(
+                metadata.clone().map(|m| m.len()).unwrap_or(0) as u32,
+                calls.using_encoded(|c| c.len() as u32)
+            ).saturating_add(Pallet::<T>::calls_weight(calls))
+        )]
+        pub fn operate_multisig_batch(
+            caller: OriginFor<T>,
+            core_id: T::CoreId,
+            metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+            fee_asset: FeeAsset,
+            calls: crate::multisig::BatchCallsOf<T>,
+            depends_on: crate::multisig::DependenciesOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_operate_multisig(
+                caller,
+                core_id,
+                metadata,
+                fee_asset,
+                calls.into_inner(),
+                depends_on,
+            )
+        }
+
+        /// Clear the votes of a proposal that left the voting stage, in bounded steps
+        /// - `core_id`: Id of the core where the proposal was
+        /// - `call_hash`: Hash of the call identifying the proposal
+        /// - `limit`: Maximum number of votes to clear
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as Config>::WeightInfo::clear_multisig_votes(*limit))]
+        pub fn clear_multisig_votes(
+            caller: OriginFor<T>,
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_clear_multisig_votes(caller, core_id, call_hash, limit)
+        }
+
+        /// Dispatch a call from one of the core's derivative sub-accounts (called by a core origin)
+        /// - `index`: Index of the sub-account within the core
+        /// - `call`: Call to be dispatched with the sub-account as the signed origin
+        ///
+        /// Succeeds even if `call` fails, its result is reported in `DerivativeDispatched`.
+        #[pallet::call_index(26)]
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (
+                <T as Config>::WeightInfo::as_derivative().saturating_add(dispatch_info.weight),
+                dispatch_info.class,
+            )
+        })]
+        pub fn as_derivative(
+            origin: OriginFor<T>,
+            index: u16,
+            call: Box<<T as pallet::Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_as_derivative(origin, index, call)
+        }
+
+        /// Map a location in another chain to a member account, or remove the mapping (called by a core origin)
+        /// - `location`: The remote location, e.g. an account in another parachain
+        /// - `member`: The member account voting on behalf of the location, `None` to remove the mapping
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_remote_member())]
+        pub fn set_remote_member(
+            origin: OriginFor<T>,
+            location: Box<VersionedMultiLocation>,
+            member: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Pallet::<T>::inner_set_remote_member(origin, location, member)
+        }
+
+        /// Vote on an existing multisig proposal from another chain, as the member mapped to the origin's location
+        /// Votes are counted like the member's local votes, auto-executing the proposal if they put it past the thresholds
+        /// - `core_id`: Id of the core where the proposal is
+        /// - `call_hash`: Hash of the call identifying the proposal
+        /// - `aye`: Wheter or not to vote positively
+        /// - `max_call_weight`: Upper bound for the weight of the proposal's calls, in case the vote executes it
+        #[pallet::call_index(28)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::vote_multisig_remote()
+                .saturating_add(<T as Config>::WeightInfo::clear_multisig_votes(MAX_VOTES_CLEARED))
+                .saturating_add(*max_call_weight)
+        )]
+        pub fn vote_multisig_remote(
+            origin: OriginFor<T>,
+            core_id: T::CoreId,
+            call_hash: T::Hash,
+            aye: bool,
+            max_call_weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            Pallet::<T>::inner_vote_multisig_remote(origin, core_id, call_hash, aye, max_call_weight)
+        }
+
+        /// Register a unique human-readable handle for the core, reserving a deposit (called by a core origin)
+        /// - `handle`: The handle, made of lowercase ASCII letters, digits and dashes
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_handle())]
+        pub fn register_handle(
+            origin: OriginFor<T>,
+            handle: crate::handles::CoreHandleOf<T>,
         ) -> DispatchResult {
-            Pallet::<T>::inner_set_parameters(origin, metadata, minimum_support, required_approval, frozen_tokens)
+            Pallet::<T>::inner_register_handle(origin, handle)
+        }
+
+        /// Transfer the core's handle to another core along with its deposit (called by a core origin)
+        /// - `target_core_id`: Id of the core receiving the handle
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_handle())]
+        pub fn transfer_handle(origin: OriginFor<T>, target_core_id: T::CoreId) -> DispatchResult {
+            Pallet::<T>::inner_transfer_handle(origin, target_core_id)
+        }
+
+        /// Release the core's handle, returning its deposit (called by a core origin)
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as Config>::WeightInfo::release_handle())]
+        pub fn release_handle(origin: OriginFor<T>) -> DispatchResult {
+            Pallet::<T>::inner_release_handle(origin)
         }
     }
 }
@@ -6,9 +6,16 @@
 //! AccountId from its CoreId.
 //! This implementation abstracts on top of two lower level functions:
 //! - `lookup_core`: Used for accessing the storage and retrieving a core's AccountId.
-//! - `lookup_address`: Used for converting from a `MultiAddress::Index` that contains a CoreId to this core's AccountId.
+//! - `lookup_address`: Used for converting from a `MultiAddress::Index` that contains a CoreId, or a
+//!   `MultiAddress::Raw` that contains a core's handle, to this core's AccountId.
+//!
+//! Cores with a handle are unlooked up to their handle, see [`crate::handles`].
+//! The derivative sub-accounts of a core can be looked up with `lookup_derivative`.
 
-use crate::{Config, CoreByAccount, CoreStorage, Pallet};
+use crate::{
+    account_derivation::CoreAccountDerivation, Config, CoreByAccount, CoreHandles, CoreStorage,
+    Pallet,
+};
 use core::marker::PhantomData;
 use frame_support::error::LookupError;
 use sp_runtime::{traits::StaticLookup, MultiAddress};
@@ -19,18 +26,31 @@ impl<T: Config> Pallet<T> {
         CoreStorage::<T>::get(core_id).map(|core| core.account)
     }
 
-    /// Matches `MultiAddress` to allow for a `MultiAddress::Index` containing a CoreId to be converted
-    /// to it's derived AccountId.
+    /// Matches `MultiAddress` to allow for a `MultiAddress::Index` containing a CoreId, or a `MultiAddress::Raw`
+    /// containing a core's handle, to be converted to it's derived AccountId.
     pub fn lookup_address(a: MultiAddress<T::AccountId, T::CoreId>) -> Option<T::AccountId> {
         match a {
             MultiAddress::Id(i) => Some(i),
             MultiAddress::Index(i) => Self::lookup_core(i),
+            MultiAddress::Raw(handle) => Self::core_by_handle(&handle).and_then(Self::lookup_core),
             _ => None,
         }
     }
 }
 
-/// StaticLookup implementor using MultiAddress::Index for looking up cores by id.
+impl<T: Config> Pallet<T>
+where
+    T::AccountId: From<[u8; 32]>,
+{
+    /// Retrieves the AccountId of one of a core's derivative sub-accounts, if the core exists.
+    pub fn lookup_derivative(core_id: T::CoreId, index: u16) -> Option<T::AccountId> {
+        CoreStorage::<T>::contains_key(core_id)
+            .then(|| Self::derive_core_derivative_account(core_id, index))
+    }
+}
+
+/// StaticLookup implementor using MultiAddress::Index for looking up cores by id and MultiAddress::Raw
+/// for looking up cores by handle.
 pub struct INV4Lookup<T: Config>(PhantomData<T>);
 
 impl<T: Config> StaticLookup for INV4Lookup<T> {
@@ -43,7 +63,10 @@ impl<T: Config> StaticLookup for INV4Lookup<T> {
 
     fn unlookup(a: Self::Target) -> Self::Source {
         match CoreByAccount::<T>::get(&a) {
-            Some(core_id) => MultiAddress::Index(core_id),
+            Some(core_id) => match CoreHandles::<T>::get(core_id) {
+                Some(record) => MultiAddress::Raw(record.handle.into_inner()),
+                None => MultiAddress::Index(core_id),
+            },
             None => MultiAddress::Id(a),
         }
     }
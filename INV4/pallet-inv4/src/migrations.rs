@@ -1,10 +1,11 @@
 use super::*;
 use frame_support::{
-    dispatch::GetStorageVersion,
-    traits::{Get, OnRuntimeUpgrade},
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
     weights::Weight,
 };
 use log::{info, warn};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
 
 pub mod v1 {
 
@@ -17,7 +18,7 @@ pub mod v1 {
     pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
     impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
         #[cfg(feature = "try-runtime")]
-        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, &'static str> {
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, TryRuntimeError> {
             frame_support::ensure!(
                 Pallet::<T>::current_storage_version() == 0,
                 "Required v0 before upgrading to v1"
@@ -43,7 +44,7 @@ pub mod v1 {
         }
 
         #[cfg(feature = "try-runtime")]
-        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), &'static str> {
+        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), TryRuntimeError> {
             frame_support::ensure!(
                 Pallet::<T>::on_chain_storage_version() == 1,
                 "v1 not applied"
@@ -88,7 +89,7 @@ pub mod v2 {
     pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
     impl<T: Config + orml_tokens2::Config> OnRuntimeUpgrade for MigrateToV2<T> {
         #[cfg(feature = "try-runtime")]
-        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, &'static str> {
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, TryRuntimeError> {
             frame_support::ensure!(
                 Pallet::<T>::current_storage_version() == 1,
                 "Required v1 before upgrading to v2"
@@ -114,7 +115,7 @@ pub mod v2 {
         }
 
         #[cfg(feature = "try-runtime")]
-        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), &'static str> {
+        fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), TryRuntimeError> {
             frame_support::ensure!(
                 Pallet::<T>::on_chain_storage_version() == 2,
                 "v2 not applied"
@@ -124,3 +125,243 @@ pub mod v2 {
         }
     }
 }
+
+pub mod v3 {
+    use super::*;
+    use crate::{
+        fee_handling::FeeAsset,
+        multisig::{BoundedCallBytes, MultisigOperation},
+        voting::{Tally, Vote},
+    };
+    use codec::{Compact, Decode, Encode};
+    use frame_support::{storage_alias, Blake2_128Concat, BoundedVec};
+    use primitives::{CoreInfo, CoreMetadata};
+    use sp_runtime::Perbill;
+    use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+    /// `CoreInfo` as stored in v2, with opaque metadata.
+    #[derive(Encode, Decode)]
+    pub struct OldCoreInfo<AccountId> {
+        pub account: AccountId,
+        pub metadata: Vec<u8>,
+        pub minimum_support: Perbill,
+        pub required_approval: Perbill,
+        pub frozen_tokens: bool,
+    }
+
+    /// `Tally` as stored in v2, with the vote of each member.
+    #[derive(Encode, Decode)]
+    pub struct OldTally<AccountId: Ord, Balance> {
+        pub ayes: Balance,
+        pub nays: Balance,
+        pub records: BTreeMap<AccountId, Vote<Balance>>,
+    }
+
+    /// `MultisigOperation` as stored in v2, with a single call and no dependencies.
+    #[derive(Encode, Decode)]
+    pub struct OldMultisigOperation<AccountId: Ord, Balance, Metadata> {
+        pub tally: OldTally<AccountId, Balance>,
+        pub original_caller: AccountId,
+        pub actual_call: Vec<u8>,
+        pub metadata: Option<Metadata>,
+        pub fee_asset: FeeAsset,
+    }
+
+    pub type OldMultisigOperationOf<T> = OldMultisigOperation<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        BoundedVec<u8, <T as Config>::MaxMetadata>,
+    >;
+
+    #[storage_alias]
+    pub type CoreStorage<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::CoreId,
+        OldCoreInfo<<T as frame_system::Config>::AccountId>,
+    >;
+
+    #[storage_alias]
+    pub type Multisig<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::CoreId,
+        Blake2_128Concat,
+        <T as frame_system::Config>::Hash,
+        OldMultisigOperationOf<T>,
+    >;
+
+    /// Adds the new parameters of each core with their defaults, moving the opaque metadata into the description
+    /// of the typed [`CoreMetadata`].
+    pub fn migrate_core_info<T: Config>() -> u64 {
+        let mut translated = 0u64;
+
+        crate::CoreStorage::<T>::translate::<OldCoreInfo<T::AccountId>, _>(|_, old| {
+            translated += 1;
+
+            Some(CoreInfo {
+                account: old.account,
+                metadata: CoreMetadata {
+                    // Bytes past the description length limit are dropped
+                    description: BoundedVec::truncate_from(old.metadata),
+                    ..Default::default()
+                },
+                minimum_support: old.minimum_support,
+                required_approval: old.required_approval,
+                minimum_voters: None,
+                frozen_tokens: old.frozen_tokens,
+                members_only_transfers: false,
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
+            })
+        });
+
+        translated
+    }
+
+    /// Whether a v2 proposal's call still fits in `MaxCallSize` once stored as a batch of one call.
+    pub fn fits_as_batch<T: Config>(proposal: &OldMultisigOperationOf<T>) -> bool {
+        proposal
+            .actual_call
+            .len()
+            .saturating_add(Compact(1u32).encoded_size())
+            <= <T as Config>::MaxCallSize::get() as usize
+    }
+
+    /// Stores the call of each proposal as a batch of one call and moves the votes of each member into
+    /// [`MultisigVotes`].
+    ///
+    /// Proposals that don't fit in `MaxCallSize` anymore are dropped along with their votes.
+    pub fn migrate_multisigs<T: Config>() -> (u64, u64) {
+        let mut translated = 0u64;
+        let mut votes = 0u64;
+
+        crate::Multisig::<T>::translate::<OldMultisigOperationOf<T>, _>(
+            |core_id, call_hash, old| {
+                translated += 1;
+
+                if !fits_as_batch::<T>(&old) {
+                    warn!(
+                        "Dropping proposal {:?} of core {:?} exceeding the max call size",
+                        call_hash, core_id
+                    );
+                    return None;
+                }
+
+                let mut calls = Compact(1u32).encode();
+                calls.extend(old.actual_call);
+
+                let voters = old.tally.records.len() as u32;
+
+                for (account, vote) in old.tally.records {
+                    votes += 1;
+                    MultisigVotes::<T>::insert((core_id, call_hash, account), vote);
+                }
+
+                Some(MultisigOperation {
+                    tally: Tally::from_parts(old.tally.ayes, old.tally.nays, voters),
+                    original_caller: old.original_caller,
+                    actual_call: BoundedCallBytes::<T>::truncate_from(calls),
+                    metadata: old.metadata,
+                    fee_asset: old.fee_asset,
+                    depends_on: Default::default(),
+                })
+            },
+        );
+
+        (translated, votes)
+    }
+
+    /// Fills [`MemberCores`] and [`CoreMemberCount`] from [`CoreMembers`].
+    pub fn fill_member_indexes<T: Config>() -> u64 {
+        let mut members = 0u64;
+
+        CoreMembers::<T>::iter_keys().for_each(|(core_id, member)| {
+            members += 1;
+            MemberCores::<T>::insert(member, core_id, ());
+            CoreMemberCount::<T>::mutate(core_id, |count| *count = count.saturating_add(1));
+        });
+
+        members
+    }
+
+    pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "Required v2 before upgrading to v3"
+            );
+
+            let cores = CoreStorage::<T>::iter_keys().count() as u64;
+
+            let (proposals, votes) = Multisig::<T>::iter_values()
+                .filter(|proposal| fits_as_batch::<T>(proposal))
+                .fold((0u64, 0u64), |(proposals, votes), proposal| {
+                    (proposals + 1, votes + proposal.tally.records.len() as u64)
+                });
+
+            let members = CoreMembers::<T>::iter_keys().count() as u64;
+
+            Ok((cores, proposals, votes, members).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() == 2 {
+                let cores = migrate_core_info::<T>();
+                let (proposals, votes) = migrate_multisigs::<T>();
+                let members = fill_member_indexes::<T>();
+
+                StorageVersion::new(3).put::<Pallet<T>>();
+
+                info!("v3 applied successfully");
+                T::DbWeight::get().reads_writes(
+                    cores + proposals + members * 2 + 1,
+                    cores + proposals + votes + members * 2 + 1,
+                )
+            } else {
+                warn!("Skipping v3, should be removed");
+                T::DbWeight::get().reads(1)
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "v3 not applied"
+            );
+
+            let (cores_before, proposals_kept, votes_kept, members_before) =
+                <(u64, u64, u64, u64)>::decode(&mut &state[..])
+                    .map_err(|_| "Failed decoding the pre upgrade state")?;
+
+            frame_support::ensure!(
+                crate::CoreStorage::<T>::iter_values().count() as u64 == cores_before,
+                "Not all cores were translated"
+            );
+
+            frame_support::ensure!(
+                crate::Multisig::<T>::iter_values().count() as u64 == proposals_kept,
+                "Not all proposals were translated"
+            );
+
+            frame_support::ensure!(
+                MultisigVotes::<T>::iter_keys().count() as u64 == votes_kept,
+                "Not all votes were moved"
+            );
+
+            frame_support::ensure!(
+                MemberCores::<T>::iter_keys().count() as u64 == members_before
+                    && CoreMemberCount::<T>::iter_values()
+                        .map(|count| count as u64)
+                        .sum::<u64>()
+                        == members_before,
+                "Member indexes don't match CoreMembers"
+            );
+
+            Ok(())
+        }
+    }
+}
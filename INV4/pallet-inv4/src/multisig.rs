@@ -7,15 +7,23 @@
 //! ### Core functionalities:
 //! - Minting/Burning voting tokens to existing and new members.
 //! - Handling proposal votes.
-//! - Dispatching approved proposals when both support and approval meet/exceed their minimum required thresholds.
+//! - Dispatching approved proposals when both support and approval meet/exceed their minimum required thresholds
+//!   and enough members voted.
+//! - Batching multiple calls in a single proposal, dispatched atomically.
 //! - Canceling proposals.
+//! - Amending the metadata of pending proposals.
+//! - Deferring the execution of passed proposals until their dependencies are done, see [`crate::dependencies`].
+//! - Enforcing the core's proposal limits, see [`crate::proposal_limits`].
 
 use super::pallet::{self, *};
 use crate::{
     account_derivation::CoreAccountDerivation,
+    archive::ProposalOutcome,
+    dependencies::DependencyStatus,
     fee_handling::{FeeAsset, FeeAssetNegativeImbalance, MultisigFeeHandler},
     origin::{ensure_multisig, INV4Origin},
     voting::{Tally, Vote},
+    WeightInfo,
 };
 use codec::DecodeLimit;
 use core::{
@@ -23,6 +31,7 @@ use core::{
     iter::Sum,
 };
 use frame_support::{
+    dispatch::GetDispatchInfo,
     pallet_prelude::*,
     traits::{
         fungibles::{Inspect, Mutate},
@@ -30,35 +39,47 @@ use frame_support::{
         Currency, ExistenceRequirement, VoteTally, WithdrawReasons,
     },
     weights::WeightToFee,
-    BoundedBTreeMap,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 use sp_runtime::{
     traits::{Hash, Zero},
     Perbill,
 };
-use sp_std::{boxed::Box, collections::btree_map::BTreeMap};
+use sp_std::vec::Vec;
 
 /// Maximum size of call we store is 50kb.
 pub const MAX_SIZE: u32 = 50 * 1024;
 
+/// Maximum amount of votes cleared when a proposal leaves the voting stage, the rest are left to `clear_multisig_votes`.
+pub const MAX_VOTES_CLEARED: u32 = 100;
+
 pub type BoundedCallBytes<T> = BoundedVec<u8, <T as Config>::MaxCallSize>;
 
+/// Calls of a batch proposal.
+pub type BatchCallsOf<T> = BoundedVec<<T as Config>::RuntimeCall, <T as Config>::MaxBatchCalls>;
+
 /// Details of a multisig operation.
 #[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
-pub struct MultisigOperation<AccountId, TallyOf, Call, Metadata> {
+pub struct MultisigOperation<AccountId, TallyOf, Call, Metadata, Dependencies> {
     pub tally: TallyOf,
     pub original_caller: AccountId,
+    /// Encoded list of calls, dispatched atomically. Single call proposals are a batch of one call.
     pub actual_call: Call,
     pub metadata: Option<Metadata>,
     pub fee_asset: FeeAsset,
+    /// Call hashes of the proposals that must execute successfully before this one.
+    pub depends_on: Dependencies,
 }
 
+pub type DependenciesOf<T> =
+    BoundedVec<<T as frame_system::Config>::Hash, <T as pallet::Config>::MaxDependencies>;
+
 pub type MultisigOperationOf<T> = MultisigOperation<
     <T as frame_system::Config>::AccountId,
     Tally<T>,
     BoundedCallBytes<T>,
     BoundedVec<u8, <T as pallet::Config>::MaxMetadata>,
+    DependenciesOf<T>,
 >;
 
 impl<T: Config> Pallet<T>
@@ -78,6 +99,9 @@ where
         let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
         let core_id = core_origin.id;
 
+        // Make sure the core's max supply is respected.
+        Self::ensure_within_max_supply(core_id, amount)?;
+
         // Mint the core's voting token to the target.
         T::AssetsProvider::mint_into(core_id, &target, amount)?;
 
@@ -100,6 +124,9 @@ where
         let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
         let core_id = core_origin.id;
 
+        // Tokens locked by vesting schedules can't be burned.
+        Self::ensure_unlocked(core_id, &target, amount)?;
+
         // Burn the core's voting token from the target.
         T::AssetsProvider::burn_from(
             core_id,
@@ -118,16 +145,25 @@ where
         Ok(())
     }
 
-    /// Inner function for the operate_multisig call.
+    /// Inner function for the operate_multisig and operate_multisig_batch calls.
     pub(crate) fn inner_operate_multisig(
         caller: OriginFor<T>,
         core_id: T::CoreId,
         metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
         fee_asset: FeeAsset,
-        call: Box<<T as Config>::RuntimeCall>,
+        calls: Vec<<T as Config>::RuntimeCall>,
+        depends_on: DependenciesOf<T>,
     ) -> DispatchResultWithPostInfo {
         let owner = ensure_signed(caller)?;
 
+        ensure!(!calls.is_empty(), Error::<T>::EmptyBatch);
+
+        // Weight of the extrinsic without the dispatched calls
+        let base_weight = <T as Config>::WeightInfo::operate_multisig(
+            metadata.as_ref().map(|m| m.len()).unwrap_or(0) as u32,
+            calls.using_encoded(|c| c.len() as u32),
+        );
+
         // Get the voting token balance of the caller
         let owner_balance: BalanceOf<T> = T::AssetsProvider::balance(core_id, &owner);
 
@@ -141,7 +177,7 @@ where
         let total_issuance: BalanceOf<T> = T::AssetsProvider::total_issuance(core_id);
 
         // Compute the call hash
-        let call_hash = <<T as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+        let call_hash = Self::proposal_hash(&calls);
 
         // Make sure this exact multisig call doesn't already exist
         ensure!(
@@ -149,32 +185,74 @@ where
             Error::<T>::MultisigCallAlreadyExists
         );
 
+        let support = Perbill::from_rational(owner_balance, total_issuance);
+
+        // Proposals depending on a failed proposal would be canceled right away
+        let dependency_status = Self::dependency_status(core_id, &depends_on);
+
+        ensure!(
+            !matches!(dependency_status, DependencyStatus::Failed(_)),
+            Error::<T>::DependencyFailed
+        );
+
         // If caller has enough balance to meet/exeed the threshold, then go ahead and execute the call now
         // There is no need to check against required_approval as it's assumed the caller is voting aye
-        if Perbill::from_rational(owner_balance, total_issuance) >= minimum_support {
-            let dispatch_result =
-                crate::dispatch::dispatch_call::<T>(core_id, &fee_asset, *call.clone());
+        // The caller is the only voter so far, so the core's minimum voters must be met by a single vote
+        // Calls with pending dependencies always go through the voting stage
+        // Calls executed right away are exempt from the core's proposal limits, which are only checked when the
+        // proposal goes into voting, as the proposer alone already meets the core's voting requirements
+        if support >= minimum_support
+            && Self::minimum_voters_reached(core_id, 1)
+            && dependency_status == DependencyStatus::Ready
+        {
+            let (dispatch_result, failed_call) =
+                crate::dispatch::dispatch_call::<T>(core_id, support, &fee_asset, &calls);
+
+            Self::record_result(core_id, call_hash, dispatch_result.is_ok());
+            Self::archive_proposal(
+                core_id,
+                call_hash,
+                owner.clone(),
+                &Tally::from_parts(owner_balance, Zero::zero(), 1),
+                ProposalOutcome::Executed,
+                Some(dispatch_result.map(|_| ()).map_err(|e| e.error)),
+            );
+
+            let call_weight = crate::dispatch::dispatched_weight(&dispatch_result);
 
             Self::deposit_event(Event::MultisigExecuted {
                 core_id,
                 executor_account: Self::derive_core_account(core_id),
                 voter: owner,
                 call_hash,
-                call: *call,
+                calls,
+                failed_call,
                 result: dispatch_result.map(|_| ()).map_err(|e| e.error),
             });
+
+            return Ok(Some(base_weight.saturating_add(call_weight)).into());
         } else {
-            // Wrap the call making sure it fits the size boundary
-            let bounded_call: BoundedCallBytes<T> = (*call)
+            // Votes of a previous proposal with the same call would be counted again
+            ensure!(
+                !MultisigVotes::<T>::contains_prefix((core_id, call_hash)),
+                Error::<T>::VotesNotCleared
+            );
+
+            // Make sure the caller is allowed to create a new proposal, taking the deposit
+            Self::register_proposal(core_id, call_hash, &owner, owner_balance)?;
+
+            Self::register_dependencies(core_id, &depends_on)?;
+
+            // Wrap the calls making sure they fit the size boundary
+            let bounded_call: BoundedCallBytes<T> = calls
                 .encode()
                 .try_into()
                 .map_err(|_| Error::<T>::MaxCallLengthExceeded)?;
 
-            let total_lenght = (bounded_call.len() as u64)
-                .saturating_add(metadata.clone().unwrap_or_default().len() as u64);
-
-            let storage_cost: BalanceOf<T> =
-                T::LengthToFee::weight_to_fee(&Weight::from_parts(total_lenght as u64, 0));
+            let storage_cost = Self::proposal_storage_cost(
+                bounded_call.len(),
+                metadata.as_ref().map(|m| m.len()).unwrap_or_default(),
+            );
 
             T::FeeCharger::handle_creation_fee(FeeAssetNegativeImbalance::Native(
                 <T as Config>::Currency::withdraw(
@@ -185,24 +263,26 @@ where
                 )?,
             ));
 
+            // The caller votes aye on their own proposal
+            let mut tally = Tally::from_parts(Zero::zero(), Zero::zero(), 0);
+            tally.process_vote(
+                core_id,
+                call_hash,
+                owner.clone(),
+                Some(Vote::Aye(owner_balance)),
+            )?;
+
             // Insert proposal in storage, it's now in the voting stage
             Multisig::<T>::insert(
                 core_id,
                 call_hash,
                 MultisigOperation {
-                    tally: Tally::from_parts(
-                        owner_balance,
-                        Zero::zero(),
-                        BoundedBTreeMap::try_from(BTreeMap::from([(
-                            owner.clone(),
-                            Vote::Aye(owner_balance),
-                        )]))
-                        .map_err(|_| Error::<T>::MaxCallersExceeded)?,
-                    ),
+                    tally,
                     original_caller: owner.clone(),
                     actual_call: bounded_call,
                     metadata,
                     fee_asset,
+                    depends_on,
                 },
             );
 
@@ -215,7 +295,7 @@ where
             });
         }
 
-        Ok(().into())
+        Ok(Some(base_weight).into())
     }
 
     /// Inner function for the vote_multisig call.
@@ -224,10 +304,32 @@ where
         core_id: T::CoreId,
         call_hash: T::Hash,
         aye: bool,
+        max_call_weight: Weight,
     ) -> DispatchResultWithPostInfo {
-        Multisig::<T>::try_mutate_exists(core_id, call_hash, |data| {
-            let owner = ensure_signed(caller.clone())?;
+        let owner = ensure_signed(caller)?;
 
+        Self::do_vote_multisig(
+            owner,
+            core_id,
+            call_hash,
+            aye,
+            max_call_weight,
+            <T as Config>::WeightInfo::vote_multisig(),
+        )
+    }
+
+    /// Adds the vote of a member to a proposal, executing it if the vote puts it past the thresholds.
+    /// Shared by local votes and votes arriving from other chains, see [`crate::remote_voting`].
+    /// `base_weight` is the weight of the vote itself, excluding the calls executed by it.
+    pub(crate) fn do_vote_multisig(
+        owner: T::AccountId,
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        aye: bool,
+        max_call_weight: Weight,
+        base_weight: Weight,
+    ) -> DispatchResultWithPostInfo {
+        Multisig::<T>::try_mutate_exists(core_id, call_hash, |data| {
             // Get the voting token balance of the caller
             let voter_balance: BalanceOf<T> = T::AssetsProvider::balance(core_id, &owner);
 
@@ -249,56 +351,239 @@ where
             };
 
             // Mutate tally with the new vote
-            old_data
-                .tally
-                .process_vote(owner.clone(), Some(new_vote_record))?;
+            old_data.tally.process_vote(
+                core_id,
+                call_hash,
+                owner.clone(),
+                Some(new_vote_record),
+            )?;
 
             let support = old_data.tally.support(core_id);
             let approval = old_data.tally.approval(core_id);
 
+            let voters = old_data.tally.voters;
+
             // Check if the multisig proposal passes the thresholds with the added vote
-            if (support >= minimum_support) && (approval >= required_approval) {
-                // Decode the call
-                let decoded_call = <T as Config>::RuntimeCall::decode_all_with_depth_limit(
-                    sp_api::MAX_EXTRINSIC_DEPTH / 4,
-                    &mut &old_data.actual_call[..],
-                )
-                .map_err(|_| Error::<T>::FailedDecodingCall)?;
+            let passed = (support >= minimum_support)
+                && (approval >= required_approval)
+                && Self::minimum_voters_reached(core_id, voters);
+
+            match Self::dependency_status(core_id, &old_data.depends_on) {
+                // If the proposal thresholds are met, remove proposal from storage and execute it
+                DependencyStatus::Ready if passed => {
+                    let call_weight = Self::execute_proposal(
+                        core_id,
+                        call_hash,
+                        old_data,
+                        support,
+                        owner,
+                        max_call_weight,
+                    )?;
+
+                    return Ok(Some(base_weight.saturating_add(call_weight)).into());
+                }
+
+                // If a dependency failed, the proposal can't be executed anymore
+                DependencyStatus::Failed(dependency) => {
+                    let clear_weight =
+                        Self::cancel_failed_proposal(core_id, call_hash, old_data, dependency);
+
+                    return Ok(Some(base_weight.saturating_add(clear_weight)).into());
+                }
+
+                // If the thresholds aren't met or the dependencies are pending, update storage with the new tally
+                dependency_status => {
+                    *data = Some(old_data.clone());
+
+                    Self::deposit_event(Event::MultisigVoteAdded {
+                        core_id,
+                        executor_account: Self::derive_core_account(core_id),
+                        voter: owner,
+                        votes_added: new_vote_record,
+                        current_votes: old_data.tally,
+                        call_hash,
+                    });
+
+                    if passed && dependency_status == DependencyStatus::Pending {
+                        Self::deposit_event(Event::MultisigExecutionDeferred {
+                            core_id,
+                            call_hash,
+                        });
+                    }
+                }
+            }
 
-                // If the proposal thresholds are met, remove proposal from storage
-                *data = None;
+            Ok(Some(base_weight).into())
+        })
+    }
 
-                // Dispatch the call and get the result
-                let dispatch_result = crate::dispatch::dispatch_call::<T>(
-                    core_id,
-                    &old_data.fee_asset,
-                    decoded_call.clone(),
+    /// Inner function for the execute_multisig call.
+    pub(crate) fn inner_execute_multisig(
+        caller: OriginFor<T>,
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        max_call_weight: Weight,
+    ) -> DispatchResultWithPostInfo {
+        let executor = ensure_signed(caller)?;
+
+        let proposal =
+            Multisig::<T>::get(core_id, call_hash).ok_or(Error::<T>::MultisigCallNotFound)?;
+
+        match Self::dependency_status(core_id, &proposal.depends_on) {
+            DependencyStatus::Ready => {
+                // Get the minimum support and required approval values of the target core
+                let (minimum_support, required_approval) =
+                    Pallet::<T>::minimum_support_and_required_approval(core_id)
+                        .ok_or(Error::<T>::CoreNotFound)?;
+
+                let support = proposal.tally.support(core_id);
+                let approval = proposal.tally.approval(core_id);
+                let voters = proposal.tally.voters;
+
+                ensure!(
+                    (support >= minimum_support)
+                        && (approval >= required_approval)
+                        && Self::minimum_voters_reached(core_id, voters),
+                    Error::<T>::MultisigNotApproved
                 );
 
-                Self::deposit_event(Event::MultisigExecuted {
-                    core_id,
-                    executor_account: Self::derive_core_account(core_id),
-                    voter: owner,
-                    call_hash,
-                    call: decoded_call,
-                    result: dispatch_result.map(|_| ()).map_err(|e| e.error),
-                });
-            } else {
-                // If the thresholds aren't met, update storage with the new tally
-                *data = Some(old_data.clone());
+                Multisig::<T>::remove(core_id, call_hash);
 
-                Self::deposit_event(Event::MultisigVoteAdded {
+                let call_weight = Self::execute_proposal(
                     core_id,
-                    executor_account: Self::derive_core_account(core_id),
-                    voter: owner,
-                    votes_added: new_vote_record,
-                    current_votes: old_data.tally,
                     call_hash,
-                });
+                    proposal,
+                    support,
+                    executor,
+                    max_call_weight,
+                )?;
+
+                Ok(
+                    Some(<T as Config>::WeightInfo::execute_multisig().saturating_add(call_weight))
+                        .into(),
+                )
             }
 
-            Ok(().into())
-        })
+            DependencyStatus::Failed(dependency) => {
+                Multisig::<T>::remove(core_id, call_hash);
+
+                let clear_weight =
+                    Self::cancel_failed_proposal(core_id, call_hash, proposal, dependency);
+
+                Ok(
+                    Some(
+                        <T as Config>::WeightInfo::execute_multisig().saturating_add(clear_weight),
+                    )
+                    .into(),
+                )
+            }
+
+            DependencyStatus::Pending => Err(Error::<T>::DependenciesPending.into()),
+        }
+    }
+
+    /// Executes a passed proposal that was removed from storage, returning the actual weight of its calls and of
+    /// clearing its votes.
+    ///
+    /// Fails if the weight of the calls exceeds `max_call_weight`, as the caller didn't pay for it.
+    fn execute_proposal(
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        proposal: MultisigOperationOf<T>,
+        support: Perbill,
+        voter: T::AccountId,
+        max_call_weight: Weight,
+    ) -> Result<Weight, DispatchError> {
+        // Decode the calls
+        let decoded_calls = Self::decode_proposal_calls(&proposal.actual_call)
+            .ok_or(Error::<T>::FailedDecodingCall)?;
+
+        ensure!(
+            Self::calls_weight(&decoded_calls).all_lte(max_call_weight),
+            Error::<T>::MaxCallWeightExceeded
+        );
+
+        Self::release_proposal(core_id, call_hash, &proposal.original_caller, false);
+        Self::resolve_dependencies(core_id, &proposal.depends_on);
+
+        // Dispatch the calls and get the results
+        let (dispatch_result, failed_call) = crate::dispatch::dispatch_call::<T>(
+            core_id,
+            support,
+            &proposal.fee_asset,
+            &decoded_calls,
+        );
+
+        Self::record_result(core_id, call_hash, dispatch_result.is_ok());
+        Self::archive_proposal(
+            core_id,
+            call_hash,
+            proposal.original_caller,
+            &proposal.tally,
+            ProposalOutcome::Executed,
+            Some(dispatch_result.map(|_| ()).map_err(|e| e.error)),
+        );
+
+        let clear_weight = Self::clear_votes(core_id, call_hash);
+
+        let call_weight = crate::dispatch::dispatched_weight(&dispatch_result);
+
+        Self::deposit_event(Event::MultisigExecuted {
+            core_id,
+            executor_account: Self::derive_core_account(core_id),
+            voter,
+            call_hash,
+            calls: decoded_calls,
+            failed_call,
+            result: dispatch_result.map(|_| ()).map_err(|e| e.error),
+        });
+
+        Ok(call_weight.saturating_add(clear_weight))
+    }
+
+    /// Cancels a proposal that was removed from storage because one of its dependencies failed, returning the
+    /// actual weight of clearing its votes.
+    fn cancel_failed_proposal(
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        proposal: MultisigOperationOf<T>,
+        dependency: T::Hash,
+    ) -> Weight {
+        // The proposer is not at fault here, so the deposit is returned
+        Self::release_proposal(core_id, call_hash, &proposal.original_caller, false);
+        Self::resolve_dependencies(core_id, &proposal.depends_on);
+
+        // Proposals depending on this one fail as well
+        Self::record_result(core_id, call_hash, false);
+        Self::archive_proposal(
+            core_id,
+            call_hash,
+            proposal.original_caller,
+            &proposal.tally,
+            ProposalOutcome::DependencyFailed,
+            None,
+        );
+
+        let clear_weight = Self::clear_votes(core_id, call_hash);
+
+        Self::deposit_event(Event::MultisigDependencyFailed {
+            core_id,
+            call_hash,
+            dependency,
+        });
+
+        clear_weight
+    }
+
+    /// Clears the votes of a proposal that left the voting stage, up to [`MAX_VOTES_CLEARED`] of them, returning
+    /// the actual weight of doing so.
+    ///
+    /// Votes past the limit must be cleared with `clear_multisig_votes` before the same call can be proposed again.
+    fn clear_votes(core_id: T::CoreId, call_hash: T::Hash) -> Weight {
+        let cleared =
+            MultisigVotes::<T>::clear_prefix((core_id, call_hash), MAX_VOTES_CLEARED, None).unique;
+
+        <T as Config>::WeightInfo::clear_multisig_votes(cleared)
     }
 
     /// Inner function for the withdraw_token_multisig call.
@@ -314,7 +599,9 @@ where
             let mut old_data = data.take().ok_or(Error::<T>::MultisigCallNotFound)?;
 
             // Try to mutate tally to remove the vote
-            let old_vote = old_data.tally.process_vote(owner.clone(), None)?;
+            let old_vote = old_data
+                .tally
+                .process_vote(core_id, call_hash, owner.clone(), None)?;
 
             // Update storage with the new tally
             *data = Some(old_data.clone());
@@ -331,6 +618,91 @@ where
         })
     }
 
+    /// Inner function for the clear_multisig_votes call.
+    pub(crate) fn inner_clear_multisig_votes(
+        caller: OriginFor<T>,
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        limit: u32,
+    ) -> DispatchResultWithPostInfo {
+        ensure_signed(caller)?;
+
+        // Votes are still needed while the proposal is in the voting stage
+        ensure!(
+            !Multisig::<T>::contains_key(core_id, call_hash),
+            Error::<T>::MultisigStillOngoing
+        );
+
+        let cleared = MultisigVotes::<T>::drain_prefix((core_id, call_hash))
+            .take(limit as usize)
+            .count() as u32;
+
+        Self::deposit_event(Event::MultisigVotesCleared {
+            core_id,
+            call_hash,
+            cleared,
+            complete: !MultisigVotes::<T>::contains_prefix((core_id, call_hash)),
+        });
+
+        Ok(Some(<T as Config>::WeightInfo::clear_multisig_votes(cleared)).into())
+    }
+
+    /// Inner function for the amend_multisig_metadata call.
+    pub(crate) fn inner_amend_multisig_metadata(
+        caller: OriginFor<T>,
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        metadata: Option<BoundedVec<u8, T::MaxMetadata>>,
+    ) -> DispatchResultWithPostInfo {
+        let owner = ensure_signed(caller)?;
+
+        Multisig::<T>::try_mutate(core_id, call_hash, |data| {
+            let proposal = data.as_mut().ok_or(Error::<T>::MultisigCallNotFound)?;
+
+            ensure!(
+                proposal.original_caller == owner,
+                Error::<T>::NotOriginalCaller
+            );
+
+            let old_cost = Self::proposal_storage_cost(
+                proposal.actual_call.len(),
+                proposal
+                    .metadata
+                    .as_ref()
+                    .map(|m| m.len())
+                    .unwrap_or_default(),
+            );
+
+            let new_cost = Self::proposal_storage_cost(
+                proposal.actual_call.len(),
+                metadata.as_ref().map(|m| m.len()).unwrap_or_default(),
+            );
+
+            // Charge the caller for the extra storage, the storage fee is not held so shorter metadata is not refunded
+            if new_cost > old_cost {
+                T::FeeCharger::handle_creation_fee(FeeAssetNegativeImbalance::Native(
+                    <T as Config>::Currency::withdraw(
+                        &owner,
+                        new_cost - old_cost,
+                        WithdrawReasons::TRANSACTION_PAYMENT,
+                        ExistenceRequirement::KeepAlive,
+                    )?,
+                ));
+            }
+
+            // Votes are kept as the call hash being voted on doesn't change
+            proposal.metadata = metadata.clone();
+
+            Self::deposit_event(Event::MultisigMetadataAmended {
+                core_id,
+                call_hash,
+                metadata: metadata.map(|m| m.to_vec()),
+            });
+
+            Ok(().into())
+        })
+    }
+
     /// Inner function for the cancel_multisig_proposal call.
     pub(crate) fn inner_cancel_multisig_proposal(
         origin: OriginFor<T>,
@@ -340,19 +712,69 @@ where
         let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
         let core_id = core_origin.id;
 
+        let mut clear_weight = Weight::zero();
+
         // Remove the proposal from storage
-        Multisig::<T>::remove(core_id, call_hash);
+        if let Some(proposal) = Multisig::<T>::take(core_id, call_hash) {
+            Self::release_proposal(core_id, call_hash, &proposal.original_caller, true);
+            Self::resolve_dependencies(core_id, &proposal.depends_on);
+
+            // Proposals depending on a canceled proposal can't be executed anymore
+            Self::record_result(core_id, call_hash, false);
+            Self::archive_proposal(
+                core_id,
+                call_hash,
+                proposal.original_caller,
+                &proposal.tally,
+                ProposalOutcome::Canceled,
+                None,
+            );
+
+            clear_weight = Self::clear_votes(core_id, call_hash);
+        }
 
         Self::deposit_event(Event::<T>::MultisigCanceled { core_id, call_hash });
 
-        Ok(().into())
+        Ok(
+            Some(
+                <T as Config>::WeightInfo::cancel_multisig_proposal().saturating_add(clear_weight),
+            )
+            .into(),
+        )
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Hash identifying a proposal.
+    ///
+    /// Single call proposals are identified by the hash of the call itself, batches by the hash of the list of calls.
+    pub fn proposal_hash(calls: &[<T as Config>::RuntimeCall]) -> T::Hash {
+        match calls {
+            [call] => <<T as frame_system::Config>::Hashing as Hash>::hash_of(call),
+            calls => <<T as frame_system::Config>::Hashing as Hash>::hash_of(&calls),
+        }
     }
 
-    pub fn add_member(core_id: &T::CoreId, member: &T::AccountId) {
-        CoreMembers::<T>::insert(core_id, member, ())
+    /// Decodes the calls of a proposal, `None` if they can't be decoded anymore, e.g. after a runtime upgrade.
+    pub fn decode_proposal_calls(encoded: &[u8]) -> Option<Vec<<T as Config>::RuntimeCall>> {
+        Vec::<<T as Config>::RuntimeCall>::decode_all_with_depth_limit(
+            sp_api::MAX_EXTRINSIC_DEPTH / 4,
+            &mut &encoded[..],
+        )
+        .ok()
     }
 
-    pub fn remove_member(core_id: &T::CoreId, member: &T::AccountId) {
-        CoreMembers::<T>::remove(core_id, member)
+    /// Declared weight of a list of calls.
+    pub fn calls_weight(calls: &[<T as Config>::RuntimeCall]) -> Weight {
+        calls.iter().fold(Weight::zero(), |weight, call| {
+            weight.saturating_add(call.get_dispatch_info().weight)
+        })
+    }
+
+    /// Storage fee of a multisig proposal, based on the length of its call and metadata.
+    pub fn proposal_storage_cost(call_len: usize, metadata_len: usize) -> BalanceOf<T> {
+        let total_length = (call_len as u64).saturating_add(metadata_len as u64);
+
+        T::LengthToFee::weight_to_fee(&Weight::from_parts(total_length, 0))
     }
 }
@@ -7,6 +7,12 @@
 //! This is an efficient approach considering that converting from CoreId to AccountId is a one-way operation,
 //! so the origin brings the CoreId to dispatchable calls.
 //! Converting to a `RawOrigin::Signed` origin for other calls is handled in the runtime.
+//!
+//! Similarly to collective origins, the origin also carries the support of the proposal being dispatched,
+//! so calls can require a stricter threshold than the core's regular voting rules.
+//!
+//! Other pallets can accept core origins through the [`EnsureCore`], [`EnsureCoreId`] and [`EnsureCoreAccount`]
+//! `EnsureOrigin` implementations, e.g. to make a specific core the admin origin of a pallet.
 
 use crate::{
     account_derivation::CoreAccountDerivation,
@@ -14,8 +20,14 @@ use crate::{
     Config,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{error::BadOrigin, pallet_prelude::RuntimeDebug};
+use core::marker::PhantomData;
+use frame_support::{
+    error::BadOrigin,
+    pallet_prelude::RuntimeDebug,
+    traits::{EnsureOrigin, Get},
+};
 use scale_info::TypeInfo;
+use sp_runtime::Perbill;
 
 /// Origin representing a core by its id.
 #[derive(PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, Clone, RuntimeDebug)]
@@ -27,6 +39,8 @@ pub enum INV4Origin<T: pallet::Config> {
 #[derive(PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, Clone, RuntimeDebug)]
 pub struct MultisigInternalOrigin<T: pallet::Config> {
     pub id: T::CoreId,
+    /// Share of the voting token supply that approved the dispatched proposal.
+    pub support: Perbill,
 }
 
 impl<T: pallet::Config> MultisigInternalOrigin<T>
@@ -34,7 +48,14 @@ where
     T::AccountId: From<[u8; 32]>,
 {
     pub fn new(id: T::CoreId) -> Self {
-        Self { id }
+        Self {
+            id,
+            support: Perbill::zero(),
+        }
+    }
+
+    pub fn with_support(id: T::CoreId, support: Perbill) -> Self {
+        Self { id, support }
     }
 
     pub fn to_account_id(&self) -> T::AccountId {
@@ -54,3 +75,77 @@ where
         _ => Err(BadOrigin),
     }
 }
+
+/// Successful core origin used by benchmarks, carrying full support so it passes any threshold.
+#[cfg(feature = "runtime-benchmarks")]
+fn benchmark_origin<T: Config, O: From<pallet::Origin<T>>>(id: T::CoreId) -> O {
+    O::from(INV4Origin::Multisig(MultisigInternalOrigin {
+        id,
+        support: Perbill::one(),
+    }))
+}
+
+/// Ensures the origin is any core, returning its id.
+pub struct EnsureCore<T>(PhantomData<T>);
+
+impl<T: Config, O> EnsureOrigin<O> for EnsureCore<T>
+where
+    O: Into<Result<pallet::Origin<T>, O>> + From<pallet::Origin<T>>,
+{
+    type Success = T::CoreId;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().map(|o| match o {
+            INV4Origin::Multisig(internal) => internal.id,
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(benchmark_origin::<T, O>(Default::default()))
+    }
+}
+
+/// Ensures the origin is the core with id `Id`.
+pub struct EnsureCoreId<T, Id>(PhantomData<(T, Id)>);
+
+impl<T: Config, Id: Get<T::CoreId>, O> EnsureOrigin<O> for EnsureCoreId<T, Id>
+where
+    O: Into<Result<pallet::Origin<T>, O>> + From<pallet::Origin<T>>,
+{
+    type Success = ();
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().and_then(|o| match o {
+            INV4Origin::Multisig(ref internal) if internal.id == Id::get() => Ok(()),
+            o => Err(O::from(o)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(benchmark_origin::<T, O>(Id::get()))
+    }
+}
+
+/// Ensures the origin is any core, returning its derived account.
+pub struct EnsureCoreAccount<T>(PhantomData<T>);
+
+impl<T: Config, O> EnsureOrigin<O> for EnsureCoreAccount<T>
+where
+    O: Into<Result<pallet::Origin<T>, O>> + From<pallet::Origin<T>>,
+    T::AccountId: From<[u8; 32]>,
+{
+    type Success = T::AccountId;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().map(|o| match o {
+            INV4Origin::Multisig(internal) => internal.to_account_id(),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(benchmark_origin::<T, O>(Default::default()))
+    }
+}
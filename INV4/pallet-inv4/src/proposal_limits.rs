@@ -0,0 +1,184 @@
+//! Proposal Rate Limiting.
+//!
+//! ## Overview
+//!
+//! This module allows cores to protect themselves from members flooding them with proposals.
+//! Cores can require a minimum voting token balance to create proposals, limit the amount of proposals each member
+//! can have open at the same time, enforce a cooldown between proposals of the same member and take a deposit that
+//! is returned once the proposal is executed.
+//!
+//! Limits only apply to proposals that go into the voting stage, calls executed right away by `operate_multisig`
+//! are not affected. Deposits of proposals canceled by the core are slashed to the core account when the core's
+//! limits have `slash_deposit` set.
+//!
+//! ### Core functionalities:
+//! - `inner_set_proposal_limits`: Sets or removes the core's proposal limits.
+//! - `register_proposal`: Checks the limits for a new proposal and takes its deposit.
+//! - `release_proposal`: Clears the bookkeeping of a proposal leaving the voting stage, returning or slashing its deposit.
+//!
+//! A member's last proposal is kept while it can still hold back their next proposal, that is until the member has
+//! no open proposals and the cooldown is over, or until the member leaves the core.
+
+use super::pallet::*;
+use crate::{
+    account_derivation::CoreAccountDerivation,
+    origin::{ensure_multisig, INV4Origin},
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{BalanceStatus, ReservableCurrency},
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Limits on the proposals members of a core can create.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct ProposalLimits<Balance, BlockNumber> {
+    /// Minimum voting token balance required to create a proposal.
+    pub min_balance: Balance,
+    /// Maximum amount of proposals a member can have open at the same time.
+    pub max_open_proposals: u32,
+    /// Amount of blocks a member has to wait between proposals.
+    pub cooldown: BlockNumber,
+    /// Deposit reserved from the proposer until the proposal leaves the voting stage.
+    pub deposit: Balance,
+    /// Whether the deposit of proposals canceled by the core should be slashed to the core account.
+    pub slash_deposit: bool,
+}
+
+pub type ProposalLimitsOf<T> = ProposalLimits<BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+{
+    /// Inner function for the set_proposal_limits call.
+    pub(crate) fn inner_set_proposal_limits(
+        origin: OriginFor<T>,
+        limits: Option<ProposalLimitsOf<T>>,
+    ) -> DispatchResult {
+        // Grab the core id from the origin
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(core_id),
+            Error::<T>::CoreNotFound
+        );
+
+        CoreProposalLimits::<T>::set(core_id, limits.clone());
+
+        Self::deposit_event(Event::ProposalLimitsSet { core_id, limits });
+
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T>
+where
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Checks if `proposer` can create a new proposal under the core's limits, taking the proposal deposit.
+    pub(crate) fn register_proposal(
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        proposer: &T::AccountId,
+        proposer_balance: BalanceOf<T>,
+    ) -> DispatchResult {
+        let now = frame_system::Pallet::<T>::block_number();
+        let open_proposals = OpenProposals::<T>::get(core_id, proposer);
+
+        if let Some(limits) = CoreProposalLimits::<T>::get(core_id) {
+            ensure!(
+                proposer_balance >= limits.min_balance,
+                Error::<T>::BalanceTooLowToPropose
+            );
+
+            ensure!(
+                open_proposals < limits.max_open_proposals,
+                Error::<T>::TooManyOpenProposals
+            );
+
+            if let Some(last) = LastProposal::<T>::get(core_id, proposer) {
+                ensure!(
+                    now >= last.saturating_add(limits.cooldown),
+                    Error::<T>::ProposalCooldown
+                );
+            }
+
+            if !limits.deposit.is_zero() {
+                <T as Config>::Currency::reserve(proposer, limits.deposit)?;
+                ProposalDeposits::<T>::insert(core_id, call_hash, limits.deposit);
+            }
+        }
+
+        // Bookkeeping is done regardless of limits so they apply right away once set
+        OpenProposals::<T>::insert(core_id, proposer, open_proposals.saturating_add(1));
+        LastProposal::<T>::insert(core_id, proposer, now);
+
+        Ok(())
+    }
+
+    /// Clears the bookkeeping of a proposal leaving the voting stage.
+    ///
+    /// The deposit is returned to the proposer, unless the proposal was canceled and the core's limits
+    /// require slashing it to the core account.
+    pub(crate) fn release_proposal(
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        proposer: &T::AccountId,
+        canceled: bool,
+    ) {
+        let open_proposals = OpenProposals::<T>::mutate_exists(core_id, proposer, |open| {
+            *open = open.map(|o| o.saturating_sub(1)).filter(|o| !o.is_zero());
+            open.unwrap_or_default()
+        });
+
+        // The last proposal of a member with no open proposals is only kept while it's still in its cooldown
+        if open_proposals.is_zero() {
+            let now = frame_system::Pallet::<T>::block_number();
+            let cooldown = CoreProposalLimits::<T>::get(core_id)
+                .map(|limits| limits.cooldown)
+                .unwrap_or_default();
+
+            LastProposal::<T>::mutate_exists(core_id, proposer, |last| {
+                *last = last.filter(|last| now < last.saturating_add(cooldown));
+            });
+        }
+
+        if let Some(deposit) = ProposalDeposits::<T>::take(core_id, call_hash) {
+            let slash = canceled
+                && CoreProposalLimits::<T>::get(core_id)
+                    .map(|limits| limits.slash_deposit)
+                    .unwrap_or(false);
+
+            let slashed = if slash {
+                <T as Config>::Currency::repatriate_reserved(
+                    proposer,
+                    &Self::derive_core_account(core_id),
+                    deposit,
+                    BalanceStatus::Free,
+                )
+                .ok()
+            } else {
+                None
+            };
+
+            match slashed {
+                Some(remaining) => Self::deposit_event(Event::ProposalDepositSlashed {
+                    core_id,
+                    call_hash,
+                    proposer: proposer.clone(),
+                    amount: deposit.saturating_sub(remaining),
+                }),
+                // Return the deposit if it wasn't slashed
+                None => {
+                    <T as Config>::Currency::unreserve(proposer, deposit);
+                }
+            }
+        }
+    }
+}
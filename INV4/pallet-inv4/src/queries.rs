@@ -0,0 +1,154 @@
+//! Runtime API Queries.
+//!
+//! ## Overview
+//!
+//! Read-only queries backing the `Inv4Api` runtime API, so front-ends don't have to decode raw storage.
+//! Runtimes implement the API by forwarding to these functions.
+//!
+//! ### Core functionalities:
+//! - `core_info`: Returns the details of a core.
+//! - `cores_of`: Returns the cores an account is a member of.
+//! - `members_of`: Returns the members of a core.
+//! - `pending_proposals`: Returns the proposals of a core in the voting stage, with their calls decoded.
+//! - `vote_would_execute`: Checks if a member's vote would execute a proposal.
+
+use super::pallet::*;
+use crate::{
+    dependencies::DependencyStatus,
+    fee_handling::FeeAsset,
+    origin::INV4Origin,
+    voting::{Tally, Vote},
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{fungibles::Inspect, VoteTally},
+};
+use primitives::{CoreInfo, CoreMetadata};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+/// A proposal in the voting stage, with its calls decoded.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct PendingProposal<AccountId, Hash, Balance, Call> {
+    pub call_hash: Hash,
+    pub proposer: AccountId,
+    /// Calls of the proposal, `None` if they can't be decoded anymore, e.g. after a runtime upgrade.
+    pub calls: Option<Vec<Call>>,
+    /// Current tally of the proposal.
+    pub ayes: Balance,
+    pub nays: Balance,
+    pub voters: u32,
+    pub metadata: Option<Vec<u8>>,
+    pub fee_asset: FeeAsset,
+    pub depends_on: Vec<Hash>,
+}
+
+pub type PendingProposalOf<T> = PendingProposal<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::Hash,
+    BalanceOf<T>,
+    CallOf<T>,
+>;
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+{
+    /// Returns the details of a core.
+    pub fn core_info(
+        core_id: T::CoreId,
+    ) -> Option<
+        CoreInfo<
+            T::AccountId,
+            CoreMetadata<Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, T::Hash>,
+            BalanceOf<T>,
+        >,
+    > {
+        CoreStorage::<T>::get(core_id).map(|core| CoreInfo {
+            account: core.account,
+            metadata: CoreMetadata {
+                name: core.metadata.name.into_inner(),
+                description: core.metadata.description.into_inner(),
+                image: core.metadata.image.into_inner(),
+                website: core.metadata.website.into_inner(),
+                content_hash: core.metadata.content_hash,
+            },
+            minimum_support: core.minimum_support,
+            required_approval: core.required_approval,
+            minimum_voters: core.minimum_voters,
+            frozen_tokens: core.frozen_tokens,
+            members_only_transfers: core.members_only_transfers,
+            max_supply: core.max_supply,
+            max_supply_supermajority: core.max_supply_supermajority,
+        })
+    }
+
+    /// Returns the cores an account is a member of.
+    pub fn cores_of(account: T::AccountId) -> Vec<T::CoreId> {
+        MemberCores::<T>::iter_key_prefix(account).collect()
+    }
+
+    /// Returns the members of a core.
+    pub fn members_of(core_id: T::CoreId) -> Vec<T::AccountId> {
+        CoreMembers::<T>::iter_key_prefix(core_id).collect()
+    }
+
+    /// Returns the proposals of a core in the voting stage, with their calls decoded.
+    pub fn pending_proposals(core_id: T::CoreId) -> Vec<PendingProposalOf<T>> {
+        Multisig::<T>::iter_prefix(core_id)
+            .map(|(call_hash, proposal)| PendingProposal {
+                call_hash,
+                proposer: proposal.original_caller,
+                calls: Self::decode_proposal_calls(&proposal.actual_call),
+                ayes: proposal.tally.ayes,
+                nays: proposal.tally.nays,
+                voters: proposal.tally.voters,
+                metadata: proposal.metadata.map(|metadata| metadata.into_inner()),
+                fee_asset: proposal.fee_asset,
+                depends_on: proposal.depends_on.into_inner(),
+            })
+            .collect()
+    }
+
+    /// Checks if a member's vote would execute a pending proposal right away.
+    ///
+    /// Proposals with pending dependencies are never executed by a vote, even if they pass.
+    pub fn vote_would_execute(
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        voter: T::AccountId,
+        aye: bool,
+    ) -> bool {
+        let Some(proposal) = Multisig::<T>::get(core_id, call_hash) else {
+            return false;
+        };
+
+        let Some((minimum_support, required_approval)) =
+            Self::minimum_support_and_required_approval(core_id)
+        else {
+            return false;
+        };
+
+        let voter_balance: BalanceOf<T> = T::AssetsProvider::balance(core_id, &voter);
+
+        if voter_balance.is_zero() {
+            return false;
+        }
+
+        let vote = if aye {
+            Vote::Aye(voter_balance)
+        } else {
+            Vote::Nay(voter_balance)
+        };
+
+        let tally: Tally<T> = proposal.tally.with_vote(core_id, call_hash, &voter, vote);
+
+        tally.support(core_id) >= minimum_support
+            && tally.approval(core_id) >= required_approval
+            && Self::minimum_voters_reached(core_id, tally.voters)
+            && Self::dependency_status(core_id, &proposal.depends_on) == DependencyStatus::Ready
+    }
+}
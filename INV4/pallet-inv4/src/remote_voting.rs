@@ -0,0 +1,85 @@
+//! Cross-chain Voting.
+//!
+//! ## Overview
+//!
+//! Members of a core may hold their identity on another chain, with no local account to vote from.
+//! Each core can map remote XCM locations to local member accounts, so votes sent through an XCM `Transact`
+//! from a mapped location are counted as votes of that member, exactly like local votes.
+//!
+//! The origin of remote votes is checked with the runtime's `RemoteVoteOrigin`, e.g. `pallet_xcm::EnsureXcm`,
+//! which yields the location the message came from.
+//!
+//! ### Core functionalities:
+//! - `set_remote_member`: Maps a remote location to a member account, or removes the mapping (called by a core origin).
+//! - `vote_multisig_remote`: Votes on a proposal as the member mapped to the origin's location.
+
+use super::pallet::*;
+use crate::{
+    origin::{ensure_multisig, INV4Origin},
+    WeightInfo,
+};
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_std::{boxed::Box, convert::TryFrom};
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    T::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the set_remote_member call.
+    pub(crate) fn inner_set_remote_member(
+        origin: OriginFor<T>,
+        location: Box<VersionedMultiLocation>,
+        member: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        ensure!(
+            CoreStorage::<T>::contains_key(core_id),
+            Error::<T>::CoreNotFound
+        );
+
+        let location =
+            MultiLocation::try_from(*location).map_err(|_| Error::<T>::UnsupportedXcmVersion)?;
+
+        match &member {
+            Some(member) => RemoteMembers::<T>::insert(core_id, location, member),
+            None => RemoteMembers::<T>::remove(core_id, location),
+        }
+
+        Self::deposit_event(Event::RemoteMemberSet {
+            core_id,
+            location,
+            member,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the vote_multisig_remote call.
+    pub(crate) fn inner_vote_multisig_remote(
+        origin: OriginFor<T>,
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        aye: bool,
+        max_call_weight: Weight,
+    ) -> DispatchResultWithPostInfo {
+        let location = T::RemoteVoteOrigin::ensure_origin(origin)?;
+
+        let member =
+            RemoteMembers::<T>::get(core_id, location).ok_or(Error::<T>::RemoteMemberNotFound)?;
+
+        Self::do_vote_multisig(
+            member,
+            core_id,
+            call_hash,
+            aye,
+            max_call_weight,
+            <T as Config>::WeightInfo::vote_multisig_remote(),
+        )
+    }
+}
@@ -18,7 +18,7 @@ use sp_runtime::{AccountId32, BuildStorage};
 use sp_std::{convert::TryInto, vec};
 
 type Block = frame_system::mocking::MockBlock<Test>;
-type Balance = u128;
+pub type Balance = u128;
 
 type AccountId = AccountId32;
 
@@ -38,6 +38,7 @@ frame_support::construct_runtime!(
         AssetRegistry: orml_asset_registry,
         CoreAssets: orml_tokens2,
         INV4: pallet,
+        MsgQueue: super::xcm_mock::mock_msg_queue,
     }
 );
 
@@ -97,72 +98,6 @@ impl Contains<AccountId> for CoreDustRemovalWhitelist {
     }
 }
 
-pub struct DisallowIfFrozen;
-impl
-    orml_traits2::currency::OnTransfer<
-        <Test as frame_system::Config>::AccountId,
-        <Test as pallet::Config>::CoreId,
-        Balance,
-    > for DisallowIfFrozen
-{
-    fn on_transfer(
-        currency_id: <Test as pallet::Config>::CoreId,
-        _from: &AccountId,
-        _to: &AccountId,
-        _amount: Balance,
-    ) -> sp_std::result::Result<(), orml_traits::parameters::sp_runtime::DispatchError> {
-        if let Some(true) = INV4::is_asset_frozen(currency_id) {
-            Err(sp_runtime::DispatchError::Token(
-                sp_runtime::TokenError::Frozen,
-            ))
-        } else {
-            Ok(())
-        }
-    }
-}
-
-pub struct HandleNewMembers;
-impl
-    orml_traits2::Happened<(
-        <Test as frame_system::Config>::AccountId,
-        <Test as pallet::Config>::CoreId,
-    )> for HandleNewMembers
-{
-    fn happened((member, core_id): &(AccountId, <Test as pallet::Config>::CoreId)) {
-        INV4::add_member(core_id, member)
-    }
-}
-
-pub struct HandleRemovedMembers;
-impl
-    orml_traits2::Happened<(
-        <Test as frame_system::Config>::AccountId,
-        <Test as pallet::Config>::CoreId,
-    )> for HandleRemovedMembers
-{
-    fn happened((member, core_id): &(AccountId, <Test as pallet::Config>::CoreId)) {
-        INV4::remove_member(core_id, member)
-    }
-}
-
-pub struct INV4TokenHooks;
-impl
-    orml_traits2::currency::MutationHooks<
-        <Test as frame_system::Config>::AccountId,
-        <Test as pallet::Config>::CoreId,
-        Balance,
-    > for INV4TokenHooks
-{
-    type PreTransfer = DisallowIfFrozen;
-    type OnDust = ();
-    type OnSlash = ();
-    type PreDeposit = ();
-    type PostDeposit = ();
-    type PostTransfer = ();
-    type OnNewTokenAccount = HandleNewMembers;
-    type OnKilledTokenAccount = HandleRemovedMembers;
-}
-
 orml_traits2::parameter_type_with_key! {
     pub CoreExistentialDeposits: |_currency_id: <Test as pallet::Config>::CoreId| -> Balance {
         CExistentialDeposit::get()
@@ -180,12 +115,11 @@ impl orml_tokens2::Config for Test {
     type MaxReserves = ConstU32<0u32>;
     type DustRemovalWhitelist = CoreDustRemovalWhitelist;
     type ReserveIdentifier = [u8; 8];
-    type CurrencyHooks = INV4TokenHooks;
+    type CurrencyHooks = asset_hooks::INV4TokenHooks<Test>;
 }
 
 parameter_types! {
     pub const MaxMetadata: u32 = 10000;
-    pub const MaxCallers: u32 = 10000;
     pub const CoreSeedBalance: Balance = 1000000u128;
     pub const CoreCreationFee: Balance = UNIT;
     pub const GenesisHash: <Test as frame_system::Config>::Hash = H256([
@@ -211,6 +145,8 @@ parameter_types! {
     pub const MaxCallSize: u32 = 50 * 1024;
     pub const StringLimit: u32 = 2125;
     pub const TransactionByteFee: Balance = 10 * MICROUNIT;
+    pub const InvitationPeriod: u64 = 100;
+    pub const MaxVestingSchedules: u32 = 10;
 
 }
 
@@ -341,7 +277,6 @@ impl pallet::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type RuntimeCall = RuntimeCall;
-    type MaxCallers = MaxCallers;
     type CoreSeedBalance = CoreSeedBalance;
     type AssetsProvider = CoreAssets;
     type RuntimeOrigin = RuntimeOrigin;
@@ -357,19 +292,33 @@ impl pallet::Config for Test {
 
     type ParaId = ConstU32<2125>;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type InvitationPeriod = InvitationPeriod;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type MaxDependencies = ConstU32<5>;
+    type MaxBatchCalls = ConstU32<10>;
+    type MaxArchivedProposals = ConstU32<10>;
+    type RemoteVoteOrigin = super::xcm_mock::EnsureLocation;
+    type MaxHandleLength = ConstU32<32>;
+    type HandleDeposit = ConstU128<1_000_000_000_000>;
+    type MaxNameLength = ConstU32<20>;
+    type MaxDescriptionLength = ConstU32<300>;
+    type MaxImageUrlLength = ConstU32<60>;
+    type MaxWebsiteLength = ConstU32<60>;
 }
 
-pub struct ExtBuilder;
-
-impl Default for ExtBuilder {
-    fn default() -> Self {
-        ExtBuilder
-    }
+#[derive(Default)]
+pub struct ExtBuilder {
+    cores: Vec<pallet::GenesisCoreOf<Test>>,
 }
 
 pub const INITIAL_BALANCE: Balance = 100000000000000000;
 
 impl ExtBuilder {
+    pub fn with_cores(mut self, cores: Vec<pallet::GenesisCoreOf<Test>>) -> Self {
+        self.cores = cores;
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
         let mut t = frame_system::GenesisConfig::<Test>::default()
             .build_storage()
@@ -428,9 +377,36 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
+        pallet::GenesisConfig::<Test> { cores: self.cores }
+            .assimilate_storage(&mut t)
+            .unwrap();
+
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(0));
 
         ext
     }
+
+    /// Builds the test externalities and runs `test` in them, checking the pallet's storage invariants afterwards.
+    pub fn build_and_execute(self, test: impl FnOnce()) {
+        self.build().execute_with(|| {
+            test();
+            INV4::do_try_state().unwrap();
+            try_state_holders();
+        })
+    }
+}
+
+/// Checks that every holder of a core's voting token is a member of the core.
+///
+/// `do_try_state` can only check the opposite, as `fungibles` can't list the holders of an asset.
+fn try_state_holders() {
+    for (holder, core_id, account) in orml_tokens2::Accounts::<Test>::iter() {
+        if account.free.saturating_add(account.reserved) > 0 {
+            assert!(
+                CoreMembers::<Test>::contains_key(core_id, &holder),
+                "Holder of a core's voting token is not a core member"
+            );
+        }
+    }
 }
@@ -1,32 +1,66 @@
 #[allow(unused_imports)]
 mod mock;
+mod xcm_mock;
 
 extern crate alloc;
 
 use crate::{
+    archive::{ArchivedProposal, ProposalOutcome},
+    fee_handling::FeeAssetBalance,
     multisig::{BoundedCallBytes, MultisigOperation, MAX_SIZE},
-    origin::MultisigInternalOrigin,
+    origin::{EnsureCore, EnsureCoreAccount, EnsureCoreId, MultisigInternalOrigin},
+    proposal_limits::ProposalLimits,
+    queries::PendingProposal,
+    vesting::{Vesting, VestingSchedule},
     voting::{Tally, Vote},
+    xcm_conversion::{CoreLocationToAccountId, CoreOriginConverter},
     *,
 };
-use alloc::collections::BTreeMap;
 use codec::Encode;
-use frame_support::{assert_err, assert_ok, error::BadOrigin, BoundedBTreeMap};
+use frame_support::{
+    assert_err, assert_ok,
+    dispatch::GetDispatchInfo,
+    error::BadOrigin,
+    traits::{
+        fungibles, ConstU32, EnsureOrigin, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+    },
+    weights::Weight,
+};
 use frame_system::RawOrigin;
 use mock::*;
-use primitives::CoreInfo;
+use primitives::{CoreInfo, CoreMetadata, GenesisCore, MinimumVoters};
 use sp_runtime::{
-    traits::{Hash, Zero},
-    ArithmeticError, Perbill, TokenError,
+    traits::{Hash, LookupError, StaticLookup, Zero},
+    ArithmeticError, MultiAddress, Perbill, TokenError,
 };
 use sp_std::{
     convert::{TryFrom, TryInto},
     vec,
 };
+use xcm::v3::{
+    send_xcm, BodyId, BodyPart, Error as XcmError,
+    Instruction::{DescendOrigin, Transact},
+    Junction::{self, Parachain, Plurality},
+    Junctions::{X1, X2},
+    MultiLocation, OriginKind, Parent, Xcm,
+};
+use xcm_executor::traits::{ConvertLocation, ConvertOrigin};
+use xcm_mock::{mock_msg_queue, MockNet, ParaA, ParaB, ParachainXcmRouter};
+use xcm_simulator::TestExt;
+
+fn test_metadata() -> inv4_core::CoreMetadataOf<Test> {
+    CoreMetadata {
+        name: b"Test core".to_vec().try_into().unwrap(),
+        description: b"Description of the test core".to_vec().try_into().unwrap(),
+        image: b"https://test.core/logo.png".to_vec().try_into().unwrap(),
+        website: b"https://test.core".to_vec().try_into().unwrap(),
+        content_hash: Some(<Test as frame_system::Config>::Hashing::hash(b"charter")),
+    }
+}
 
 #[test]
 fn create_core_works() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
 
         assert_eq!(INV4::next_core_id(), 0u32);
@@ -35,7 +69,7 @@ fn create_core_works() {
 
         assert_ok!(INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native
@@ -47,10 +81,14 @@ fn create_core_works() {
             INV4::core_storage(0u32),
             Some(CoreInfo {
                 account: INV4::derive_core_account(0u32),
-                metadata: vec![].try_into().unwrap(),
+                metadata: Default::default(),
                 minimum_support: Perbill::from_percent(1),
                 required_approval: Perbill::from_percent(1),
+                minimum_voters: None,
                 frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
             })
         );
 
@@ -67,7 +105,7 @@ fn create_core_works() {
 
         assert_ok!(INV4::create_core(
             RawOrigin::Signed(BOB).into(),
-            vec![1, 2, 3].try_into().unwrap(),
+            test_metadata(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Relay
@@ -79,10 +117,14 @@ fn create_core_works() {
             INV4::core_storage(1u32),
             Some(CoreInfo {
                 account: INV4::derive_core_account(1u32),
-                metadata: vec![1, 2, 3].try_into().unwrap(),
+                metadata: test_metadata(),
                 minimum_support: Perbill::from_percent(100),
                 required_approval: Perbill::from_percent(100),
+                minimum_voters: None,
                 frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
             })
         );
 
@@ -93,9 +135,138 @@ fn create_core_works() {
     });
 }
 
+#[test]
+fn genesis_cores_work() {
+    ExtBuilder::default()
+        .with_cores(vec![
+            GenesisCore {
+                metadata: test_metadata(),
+                minimum_support: Perbill::from_percent(50),
+                required_approval: Perbill::from_percent(60),
+                minimum_voters: Some(MinimumVoters::Absolute(2)),
+                frozen_tokens: false,
+                members_only_transfers: true,
+                max_supply: Some(2_000_000u128),
+                members: vec![(ALICE, CoreSeedBalance::get()), (BOB, 500_000u128)],
+            },
+            GenesisCore {
+                metadata: Default::default(),
+                minimum_support: Perbill::from_percent(100),
+                required_approval: Perbill::from_percent(100),
+                minimum_voters: None,
+                frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                members: vec![(CHARLIE, CoreSeedBalance::get())],
+            },
+        ])
+        .build_and_execute(|| {
+            assert_eq!(INV4::next_core_id(), 2u32);
+
+            assert_eq!(
+                INV4::core_storage(0u32),
+                Some(CoreInfo {
+                    account: INV4::derive_core_account(0u32),
+                    metadata: test_metadata(),
+                    minimum_support: Perbill::from_percent(50),
+                    required_approval: Perbill::from_percent(60),
+                    minimum_voters: Some(MinimumVoters::Absolute(2)),
+                    frozen_tokens: false,
+                    members_only_transfers: true,
+                    max_supply: Some(2_000_000u128),
+                    max_supply_supermajority: Perbill::one(),
+                })
+            );
+            assert!(INV4::core_storage(1u32).unwrap().frozen_tokens);
+            assert!(!INV4::core_storage(1u32).unwrap().members_only_transfers);
+
+            assert_eq!(
+                INV4::core_by_account(INV4::derive_core_account(0u32)),
+                Some(0u32)
+            );
+            assert_eq!(
+                INV4::core_by_account(INV4::derive_core_account(1u32)),
+                Some(1u32)
+            );
+
+            assert_eq!(
+                CoreAssets::accounts(ALICE, 0u32).free,
+                CoreSeedBalance::get()
+            );
+            assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 500_000u128);
+            assert_eq!(
+                CoreAssets::accounts(CHARLIE, 1u32).free,
+                CoreSeedBalance::get()
+            );
+
+            let mut members = INV4::members_of(0u32);
+            members.sort();
+            assert_eq!(members, vec![ALICE, BOB]);
+            assert_eq!(INV4::members_of(1u32), vec![CHARLIE]);
+            assert_eq!(INV4::core_member_count(0u32), 2);
+            assert_eq!(INV4::cores_of(CHARLIE), vec![1u32]);
+
+            // Cores created afterwards continue from the genesis ones.
+            assert_ok!(INV4::create_core(
+                RawOrigin::Signed(ALICE).into(),
+                Default::default(),
+                Perbill::from_percent(100),
+                Perbill::from_percent(100),
+                FeeAsset::Native
+            ));
+            assert!(INV4::core_storage(2u32).is_some());
+        });
+}
+
+#[test]
+#[should_panic(expected = "Genesis core at index 1 needs at least one member")]
+fn genesis_core_without_members_fails() {
+    ExtBuilder::default()
+        .with_cores(vec![
+            GenesisCore {
+                metadata: Default::default(),
+                minimum_support: Perbill::from_percent(100),
+                required_approval: Perbill::from_percent(100),
+                minimum_voters: None,
+                frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                members: vec![(ALICE, CoreSeedBalance::get())],
+            },
+            GenesisCore {
+                metadata: Default::default(),
+                minimum_support: Perbill::from_percent(100),
+                required_approval: Perbill::from_percent(100),
+                minimum_voters: None,
+                frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                members: vec![],
+            },
+        ])
+        .build_and_execute(|| {});
+}
+
+#[test]
+#[should_panic(expected = "Genesis core at index 0 exceeds its max supply")]
+fn genesis_core_exceeding_max_supply_fails() {
+    ExtBuilder::default()
+        .with_cores(vec![GenesisCore {
+            metadata: Default::default(),
+            minimum_support: Perbill::from_percent(100),
+            required_approval: Perbill::from_percent(100),
+            minimum_voters: None,
+            frozen_tokens: true,
+            members_only_transfers: false,
+            max_supply: Some(CoreSeedBalance::get()),
+            members: vec![(ALICE, CoreSeedBalance::get()), (BOB, 1u128)],
+        }])
+        .build_and_execute(|| {});
+}
+
 #[test]
 fn create_core_fails() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         // Not enough balance for creation fee.
 
         assert_eq!(Balances::free_balance(DAVE), 0u128);
@@ -107,7 +278,7 @@ fn create_core_fails() {
         assert_err!(
             INV4::create_core(
                 RawOrigin::Signed(DAVE).into(),
-                vec![].try_into().unwrap(),
+                Default::default(),
                 Perbill::from_percent(1),
                 Perbill::from_percent(1),
                 FeeAsset::Native
@@ -125,7 +296,7 @@ fn create_core_fails() {
         assert_err!(
             INV4::create_core(
                 RawOrigin::Signed(DAVE).into(),
-                vec![].try_into().unwrap(),
+                Default::default(),
                 Perbill::from_percent(1),
                 Perbill::from_percent(1),
                 FeeAsset::Relay
@@ -140,10 +311,10 @@ fn create_core_fails() {
 
 #[test]
 fn set_parameters_works() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -152,20 +323,26 @@ fn set_parameters_works() {
 
         assert_ok!(INV4::set_parameters(
             Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
-            Some(vec![1, 2, 3].try_into().unwrap()),
+            Some(test_metadata()),
             Some(Perbill::from_percent(100)),
             Some(Perbill::from_percent(100)),
-            Some(false)
+            Some(false),
+            None,
+            None
         ));
 
         assert_eq!(
             INV4::core_storage(0u32),
             Some(CoreInfo {
                 account: INV4::derive_core_account(0u32),
-                metadata: vec![1, 2, 3].try_into().unwrap(),
+                metadata: test_metadata(),
                 minimum_support: Perbill::from_percent(100),
                 required_approval: Perbill::from_percent(100),
+                minimum_voters: None,
                 frozen_tokens: false,
+                members_only_transfers: false,
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
             })
         );
     });
@@ -173,10 +350,10 @@ fn set_parameters_works() {
 
 #[test]
 fn set_parameters_fails() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -188,10 +365,12 @@ fn set_parameters_fails() {
         assert_err!(
             INV4::set_parameters(
                 RawOrigin::Signed(ALICE).into(),
-                Some(vec![1, 2, 3].try_into().unwrap()),
+                Some(test_metadata()),
                 Some(Perbill::from_percent(100)),
                 Some(Perbill::from_percent(100)),
-                Some(false)
+                Some(false),
+                None,
+                None
             ),
             BadOrigin
         );
@@ -201,10 +380,12 @@ fn set_parameters_fails() {
         assert_err!(
             INV4::set_parameters(
                 Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
-                Some(vec![1, 2, 3].try_into().unwrap()),
+                Some(test_metadata()),
                 Some(Perbill::from_percent(100)),
                 Some(Perbill::from_percent(100)),
-                Some(false)
+                Some(false),
+                None,
+                None
             ),
             Error::<Test>::CoreNotFound
         );
@@ -213,10 +394,10 @@ fn set_parameters_fails() {
 
 #[test]
 fn token_mint_works() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -245,10 +426,10 @@ fn token_mint_works() {
 
 #[test]
 fn token_mint_fails() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -275,10 +456,10 @@ fn token_mint_fails() {
 
 #[test]
 fn token_burn_works() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -328,10 +509,10 @@ fn token_burn_works() {
 
 #[test]
 fn token_burn_fails() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(1),
             Perbill::from_percent(1),
             FeeAsset::Native,
@@ -389,605 +570,4124 @@ fn token_burn_fails() {
 }
 
 #[test]
-fn operate_multisig_works() {
-    ExtBuilder::default().build().execute_with(|| {
+fn core_token_transfer_hooks_work() {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
-            Perbill::from_percent(100),
-            Perbill::from_percent(100),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
         )
         .unwrap();
 
-        System::set_block_number(1);
+        // Tokens are frozen by default.
 
-        let call: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
+        assert_err!(
+            CoreAssets::transfer(
+                RawOrigin::Signed(ALICE).into(),
+                BOB,
+                0u32,
+                CoreSeedBalance::get()
+            ),
+            TokenError::Frozen
+        );
 
-        // Test with single voter.
+        assert_eq!(INV4::core_members(0u32, BOB), None);
 
-        assert_ok!(INV4::operate_multisig(
+        INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false),
+            None,
+            None,
+        )
+        .unwrap();
+
+        // Partial transfer adds the receiver as a member.
+
+        assert_ok!(CoreAssets::transfer(
             RawOrigin::Signed(ALICE).into(),
+            BOB,
             0u32,
-            Some(vec![1, 2, 3].try_into().unwrap()),
-            FeeAsset::Native,
-            Box::new(call.clone())
+            CoreSeedBalance::get() / 2
         ));
 
-        System::assert_has_event(
-            orml_tokens2::Event::Deposited {
-                currency_id: 0u32,
-                who: BOB,
-                amount: CoreSeedBalance::get(),
-            }
-            .into(),
-        );
-
-        System::assert_has_event(
-            Event::Minted {
-                core_id: 0u32,
-                target: BOB,
-                amount: CoreSeedBalance::get(),
-            }
-            .into(),
-        );
-
-        System::assert_has_event(
-            Event::MultisigExecuted {
-                core_id: 0u32,
-                executor_account: INV4::derive_core_account(0u32),
-                voter: ALICE,
-                call: call.clone(),
-                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call),
-                result: Ok(()),
-            }
-            .into(),
-        );
-
-        // Test with 2 voters, call should be stored for voting.
+        assert_eq!(INV4::core_members(0u32, ALICE), Some(()));
+        assert_eq!(INV4::core_members(0u32, BOB), Some(()));
+        assert_eq!(INV4::core_member_count(0u32), 2);
+        assert_eq!(INV4::member_cores(ALICE, 0u32), Some(()));
+        assert_eq!(INV4::member_cores(BOB, 0u32), Some(()));
 
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
-            ),
-            None,
-        );
+        // Transferring the whole balance removes the sender from the members.
 
-        assert_ok!(INV4::operate_multisig(
+        assert_ok!(CoreAssets::transfer(
             RawOrigin::Signed(ALICE).into(),
+            BOB,
             0u32,
-            Some(vec![1, 2, 3].try_into().unwrap()),
-            FeeAsset::Native,
-            Box::new(call.clone())
+            CoreSeedBalance::get() / 2
         ));
 
-        System::assert_has_event(
-            Event::MultisigVoteStarted {
-                core_id: 0u32,
-                executor_account: INV4::derive_core_account(0u32),
-                voter: ALICE,
-                votes_added: Vote::Aye(CoreSeedBalance::get()),
-                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call),
-            }
-            .into(),
-        );
-
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
-            ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: Some(vec![1, 2, 3].try_into().unwrap()),
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(CoreSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
-            })
-        );
+        assert_eq!(INV4::core_members(0u32, ALICE), None);
+        assert_eq!(INV4::core_members(0u32, BOB), Some(()));
+        assert_eq!(INV4::core_member_count(0u32), 1);
+        assert_eq!(INV4::member_cores(ALICE, 0u32), None);
+        assert_eq!(INV4::member_cores(BOB, 0u32), Some(()));
     });
 }
 
 #[test]
-fn operate_multisig_fails() {
-    ExtBuilder::default().build().execute_with(|| {
+fn core_token_slash_removes_member() {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
         )
         .unwrap();
 
-        System::set_block_number(1);
-
-        let call: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
-
-        // Using this call now to add a second member to the multisig.
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
-            FeeAsset::Native,
-            Box::new(call.clone()),
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1000,
+            BOB,
         )
         .unwrap();
 
-        // Not a member of the multisig
-        assert_err!(
-            INV4::operate_multisig(
-                RawOrigin::Signed(CHARLIE).into(),
-                0u32,
-                Some(vec![1, 2, 3].try_into().unwrap()),
-                FeeAsset::Native,
-                Box::new(call.clone())
-            ),
-            Error::<Test>::NoPermission
-        );
+        assert_eq!(INV4::core_member_count(0u32), 2);
 
-        // Max call length exceeded.
-        assert_err!(
-            INV4::operate_multisig(
-                RawOrigin::Signed(ALICE).into(),
-                0u32,
-                None,
-                FeeAsset::Native,
-                Box::new(
-                    frame_system::pallet::Call::<Test>::remark {
-                        remark: vec![0u8; MAX_SIZE as usize]
-                    }
-                    .into()
-                )
-            ),
-            Error::<Test>::MaxCallLengthExceeded
+        // Partial slash keeps the member.
+
+        assert_eq!(
+            <CoreAssets as orml_traits2::MultiCurrency<_>>::slash(0u32, &BOB, 400),
+            0
         );
 
-        // Multisig call already exists in storage.
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
-            FeeAsset::Native,
-            Box::new(call.clone()),
-        )
-        .unwrap();
-        assert_err!(
-            INV4::operate_multisig(
-                RawOrigin::Signed(ALICE).into(),
-                0u32,
-                None,
-                FeeAsset::Native,
-                Box::new(call.clone())
-            ),
-            Error::<Test>::MultisigCallAlreadyExists
+        assert_eq!(INV4::core_members(0u32, BOB), Some(()));
+        assert_eq!(INV4::core_member_count(0u32), 2);
+
+        // Slashing the whole balance removes the member.
+
+        assert_eq!(
+            <CoreAssets as orml_traits2::MultiCurrency<_>>::slash(0u32, &BOB, 600),
+            0
         );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0);
+        assert_eq!(INV4::core_members(0u32, BOB), None);
+        assert_eq!(INV4::core_member_count(0u32), 1);
+        assert_eq!(INV4::member_cores(BOB, 0u32), None);
+        assert_eq!(INV4::core_members(0u32, ALICE), Some(()));
     });
 }
 
 #[test]
-fn cancel_multisig_works() {
-    ExtBuilder::default().build().execute_with(|| {
+fn members_only_transfers_work() {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
-            Perbill::from_percent(100),
-            Perbill::from_percent(100),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
         )
         .unwrap();
 
-        System::set_block_number(1);
-
-        let call: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
-
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            Some(vec![1, 2, 3].try_into().unwrap()),
-            FeeAsset::Native,
-            Box::new(call.clone()),
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
         )
         .unwrap();
 
-        System::set_block_number(2);
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false),
+            Some(true),
+            None,
+        ));
 
-        INV4::operate_multisig(
+        System::assert_last_event(
+            Event::ParametersSet {
+                core_id: 0u32,
+                metadata: None,
+                minimum_support: None,
+                required_approval: None,
+                frozen_tokens: Some(false),
+                members_only_transfers: Some(true),
+            }
+            .into(),
+        );
+
+        // Transfers between members are allowed.
+
+        assert_ok!(CoreAssets::transfer(
             RawOrigin::Signed(ALICE).into(),
+            BOB,
             0u32,
-            Some(vec![1, 2, 3].try_into().unwrap()),
-            FeeAsset::Native,
-            Box::new(call.clone()),
-        )
-        .unwrap();
+            CoreSeedBalance::get() / 2
+        ));
 
-        assert_eq!(
-            INV4::multisig(
+        // Transfers to non members are not.
+
+        assert_err!(
+            CoreAssets::transfer(
+                RawOrigin::Signed(ALICE).into(),
+                CHARLIE,
                 0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+                CoreSeedBalance::get() / 2
             ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: Some(vec![1, 2, 3].try_into().unwrap()),
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(CoreSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
-            })
+            Error::<Test>::RecipientNotAllowed
         );
 
-        assert_ok!(INV4::cancel_multisig_proposal(
+        // Unless the recipient is whitelisted.
+
+        assert_err!(
+            INV4::set_transfer_whitelist(RawOrigin::Signed(ALICE).into(), CHARLIE, true),
+            BadOrigin
+        );
+
+        assert_ok!(INV4::set_transfer_whitelist(
             Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
-            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            CHARLIE,
+            true
         ));
 
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
-            ),
-            None
+        System::assert_last_event(
+            Event::TransferWhitelistSet {
+                core_id: 0u32,
+                account: CHARLIE,
+                whitelisted: true,
+            }
+            .into(),
         );
-    });
-}
 
-#[test]
-fn cancel_multisig_fails() {
-    ExtBuilder::default().build().execute_with(|| {
-        INV4::create_core(
+        assert_eq!(INV4::transfer_whitelist(0u32, CHARLIE), Some(()));
+
+        assert_ok!(CoreAssets::transfer(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
-            Perbill::from_percent(100),
-            Perbill::from_percent(100),
-            FeeAsset::Native,
-        )
-        .unwrap();
+            CHARLIE,
+            0u32,
+            CoreSeedBalance::get() / 4
+        ));
 
-        System::set_block_number(1);
+        assert_eq!(INV4::core_members(0u32, CHARLIE), Some(()));
 
-        let call: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
+        // Removing from the whitelist doesn't affect existing members.
 
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            Some(vec![1, 2, 3].try_into().unwrap()),
-            FeeAsset::Native,
-            Box::new(call.clone()),
-        )
-        .unwrap();
+        assert_ok!(INV4::set_transfer_whitelist(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CHARLIE,
+            false
+        ));
 
-        System::set_block_number(2);
+        assert_eq!(INV4::transfer_whitelist(0u32, CHARLIE), None);
 
-        INV4::operate_multisig(
+        assert_ok!(CoreAssets::transfer(
             RawOrigin::Signed(ALICE).into(),
+            CHARLIE,
             0u32,
-            Some(vec![1, 2, 3].try_into().unwrap()),
-            FeeAsset::Native,
-            Box::new(call.clone()),
-        )
-        .unwrap();
+            CoreSeedBalance::get() / 4
+        ));
 
-        // Wrong origin.
         assert_err!(
-            INV4::cancel_multisig_proposal(
-                RawOrigin::Signed(ALICE).into(),
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            CoreAssets::transfer(
+                RawOrigin::Signed(BOB).into(),
+                DAVE,
+                0u32,
+                CoreSeedBalance::get() / 4
             ),
-            BadOrigin
+            Error::<Test>::RecipientNotAllowed
         );
 
-        assert_eq!(
-            INV4::multisig(
+        // Frozen tokens take precedence.
+
+        INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_err!(
+            CoreAssets::transfer(
+                RawOrigin::Signed(BOB).into(),
+                CHARLIE,
                 0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+                CoreSeedBalance::get() / 4
             ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: Some(vec![1, 2, 3].try_into().unwrap()),
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(CoreSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
-            })
+            TokenError::Frozen
         );
     });
 }
 
 #[test]
-fn vote_multisig_works() {
-    ExtBuilder::default().build().execute_with(|| {
+fn invitations_work() {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
-            Perbill::from_percent(100),
-            Perbill::from_percent(100),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
         )
         .unwrap();
 
         System::set_block_number(1);
 
-        let call1: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
-
-        let call2: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: CHARLIE,
-        }
-        .into();
+        // BOB accepts the invitation.
 
-        // Adding BOB.
+        assert_ok!(INV4::invite_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB
+        ));
 
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
-            FeeAsset::Native,
-            Box::new(call1.clone()),
-        )
-        .unwrap();
+        System::assert_last_event(
+            Event::MemberInvited {
+                core_id: 0u32,
+                target: BOB,
+                amount: CoreSeedBalance::get(),
+                expires_at: 1 + InvitationPeriod::get(),
+            }
+            .into(),
+        );
 
-        System::set_block_number(2);
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+        assert_eq!(INV4::core_members(0u32, BOB), None);
 
-        // Adding CHARLIE
+        System::set_block_number(1 + InvitationPeriod::get());
 
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
-            FeeAsset::Native,
-            Box::new(call2.clone()),
-        )
-        .unwrap();
+        assert_ok!(INV4::accept_invitation(RawOrigin::Signed(BOB).into(), 0u32));
 
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
-            ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call2.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(CoreSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
-            })
+        System::assert_has_event(
+            Event::InvitationAccepted {
+                core_id: 0u32,
+                target: BOB,
+                amount: CoreSeedBalance::get(),
+            }
+            .into(),
         );
 
-        // BOB votes nay.
+        assert_eq!(INV4::invitations(0u32, BOB), None);
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, CoreSeedBalance::get());
+        assert_eq!(INV4::core_members(0u32, BOB), Some(()));
 
-        assert_ok!(INV4::vote_multisig(
-            RawOrigin::Signed(BOB).into(),
-            0u32,
-            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
-            false
+        // CHARLIE declines the invitation.
+
+        assert_ok!(INV4::invite_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            CHARLIE
         ));
 
-        System::assert_has_event(
-            Event::MultisigVoteAdded {
+        assert_ok!(INV4::decline_invitation(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32
+        ));
+
+        System::assert_last_event(
+            Event::InvitationDeclined {
                 core_id: 0u32,
-                executor_account: INV4::derive_core_account(0u32),
-                voter: BOB,
-                votes_added: Vote::Nay(CoreSeedBalance::get()),
-                current_votes: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    CoreSeedBalance::get(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([
-                        (ALICE, Vote::Aye(CoreSeedBalance::get())),
-                        (BOB, Vote::Nay(CoreSeedBalance::get())),
-                    ]))
-                    .unwrap(),
-                ),
-                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+                target: CHARLIE,
             }
             .into(),
         );
 
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
-            ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call2.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    CoreSeedBalance::get(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([
-                        (ALICE, Vote::Aye(CoreSeedBalance::get())),
-                        (BOB, Vote::Nay(CoreSeedBalance::get()))
-                    ]))
-                    .unwrap()
-                ),
-            })
-        );
+        assert_eq!(INV4::invitations(0u32, CHARLIE), None);
+        assert_eq!(CoreAssets::accounts(CHARLIE, 0u32).free, 0u128);
 
-        // BOB changes vote to aye, executing the call.
+        // DAVE lets the invitation expire.
 
-        assert_ok!(INV4::vote_multisig(
-            RawOrigin::Signed(BOB).into(),
+        assert_ok!(INV4::invite_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            DAVE
+        ));
+
+        System::set_block_number(2 + InvitationPeriod::get() * 2);
+
+        assert_ok!(INV4::clear_expired_invitation(
+            RawOrigin::Signed(ALICE).into(),
             0u32,
-            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
-            true
+            DAVE
         ));
 
-        System::assert_has_event(
-            Event::MultisigExecuted {
+        System::assert_last_event(
+            Event::InvitationExpired {
                 core_id: 0u32,
-                executor_account: INV4::derive_core_account(0u32),
-                voter: BOB,
-                call: call2.clone(),
-                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
-                result: Ok(()),
+                target: DAVE,
             }
             .into(),
         );
 
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
-            ),
-            None
-        );
+        assert_eq!(INV4::invitations(0u32, DAVE), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0u128);
     });
 }
 
 #[test]
-fn vote_multisig_fails() {
-    ExtBuilder::default().build().execute_with(|| {
+fn invitations_fail() {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
-            Perbill::from_percent(100),
-            Perbill::from_percent(100),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
         )
         .unwrap();
 
         System::set_block_number(1);
 
-        let call1: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
-
-        let call2: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: CHARLIE,
-        }
-        .into();
+        // Wrong origin.
 
-        // Adding BOB.
+        assert_err!(
+            INV4::invite_member(RawOrigin::Signed(ALICE).into(), CoreSeedBalance::get(), BOB),
+            BadOrigin
+        );
 
-        INV4::operate_multisig(
-            RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
-            FeeAsset::Native,
-            Box::new(call1.clone()),
-        )
-        .unwrap();
+        // No invitation.
 
-        System::set_block_number(2);
+        assert_err!(
+            INV4::accept_invitation(RawOrigin::Signed(BOB).into(), 0u32),
+            Error::<Test>::InvitationNotFound
+        );
 
-        // Adding CHARLIE
+        assert_err!(
+            INV4::decline_invitation(RawOrigin::Signed(BOB).into(), 0u32),
+            Error::<Test>::InvitationNotFound
+        );
 
-        INV4::operate_multisig(
+        INV4::invite_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        // Already invited.
+
+        assert_err!(
+            INV4::invite_member(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                CoreSeedBalance::get(),
+                BOB
+            ),
+            Error::<Test>::AlreadyInvited
+        );
+
+        // Not expired yet.
+
+        assert_err!(
+            INV4::clear_expired_invitation(RawOrigin::Signed(ALICE).into(), 0u32, BOB),
+            Error::<Test>::InvitationNotExpired
+        );
+
+        // Expired.
+
+        System::set_block_number(2 + InvitationPeriod::get());
+
+        assert_err!(
+            INV4::accept_invitation(RawOrigin::Signed(BOB).into(), 0u32),
+            Error::<Test>::InvitationExpired
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+        assert_eq!(INV4::core_members(0u32, BOB), None);
+
+        // Expired invitations can be replaced.
+
+        assert_ok!(INV4::invite_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB
+        ));
+
+        System::assert_has_event(
+            Event::InvitationExpired {
+                core_id: 0u32,
+                target: BOB,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn token_sale_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            0u32,
-            None,
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
             FeeAsset::Native,
-            Box::new(call2.clone()),
         )
         .unwrap();
 
+        System::set_block_number(1);
+
+        let price: Balance = 1_000_000;
+        let cap: Balance = CoreSeedBalance::get();
+
+        assert_ok!(INV4::open_token_sale(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            FeeAssetBalance::Native(price),
+            cap,
+            2,
+            10
+        ));
+
+        System::assert_last_event(
+            Event::TokenSaleOpened {
+                core_id: 0u32,
+                price: FeeAssetBalance::Native(price),
+                cap,
+                start: 2,
+                end: 10,
+            }
+            .into(),
+        );
+
+        System::set_block_number(2);
+
+        let core_balance = Balances::free_balance(INV4::derive_core_account(0u32));
+
+        assert_ok!(INV4::buy_tokens(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            cap / 2
+        ));
+
+        System::assert_last_event(
+            Event::TokensBought {
+                core_id: 0u32,
+                buyer: BOB,
+                amount: cap / 2,
+                cost: FeeAssetBalance::Native(price * cap / 2),
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, cap / 2);
+        assert_eq!(INV4::core_members(0u32, BOB), Some(()));
         assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            Balances::free_balance(BOB),
+            INITIAL_BALANCE - price * cap / 2
+        );
+        assert_eq!(
+            Balances::free_balance(INV4::derive_core_account(0u32)),
+            core_balance + price * cap / 2
+        );
+        assert_eq!(INV4::token_sales(0u32).unwrap().sold, cap / 2);
+
+        assert_ok!(INV4::close_token_sale(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into()
+        ));
+
+        System::assert_last_event(
+            Event::TokenSaleClosed {
+                core_id: 0u32,
+                sold: cap / 2,
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::token_sales(0u32), None);
+
+        // Sale paid in the relay token.
+
+        assert_ok!(INV4::open_token_sale(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            FeeAssetBalance::Relay(price),
+            cap,
+            2,
+            10
+        ));
+
+        assert_ok!(INV4::buy_tokens(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            cap
+        ));
+
+        System::assert_last_event(
+            Event::TokensBought {
+                core_id: 0u32,
+                buyer: CHARLIE,
+                amount: cap,
+                cost: FeeAssetBalance::Relay(price * cap),
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(CHARLIE, 0u32).free, cap);
+        assert_eq!(
+            Tokens::accounts(CHARLIE, RELAY_ASSET_ID).free,
+            INITIAL_BALANCE - price * cap
+        );
+        assert_eq!(
+            Tokens::accounts(INV4::derive_core_account(0u32), RELAY_ASSET_ID).free,
+            price * cap
+        );
+    });
+}
+
+#[test]
+fn token_sale_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(5);
+
+        // Wrong origin.
+
+        assert_err!(
+            INV4::open_token_sale(
+                RawOrigin::Signed(ALICE).into(),
+                FeeAssetBalance::Native(10),
+                CoreSeedBalance::get(),
+                5,
+                10
             ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call2.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(CoreSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
-            })
+            BadOrigin
         );
 
-        // Not a member of the multisig.
+        // Invalid periods.
+
         assert_err!(
-            INV4::vote_multisig(
-                RawOrigin::Signed(DAVE).into(),
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
-                true
+            INV4::open_token_sale(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                FeeAssetBalance::Native(10),
+                CoreSeedBalance::get(),
+                10,
+                6
             ),
-            Error::<Test>::NoPermission
+            Error::<Test>::InvalidTokenSalePeriod
         );
 
-        // Call not found.
         assert_err!(
-            INV4::vote_multisig(
+            INV4::open_token_sale(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                FeeAssetBalance::Native(10),
+                CoreSeedBalance::get(),
+                1,
+                4
+            ),
+            Error::<Test>::InvalidTokenSalePeriod
+        );
+
+        // No sale.
+
+        assert_err!(
+            INV4::buy_tokens(RawOrigin::Signed(BOB).into(), 0u32, 1),
+            Error::<Test>::TokenSaleNotFound
+        );
+
+        assert_err!(
+            INV4::close_token_sale(Origin::Multisig(MultisigInternalOrigin::new(0u32)).into()),
+            Error::<Test>::TokenSaleNotFound
+        );
+
+        INV4::open_token_sale(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            FeeAssetBalance::Native(10),
+            CoreSeedBalance::get(),
+            6,
+            10,
+        )
+        .unwrap();
+
+        // Sale already exists.
+
+        assert_err!(
+            INV4::open_token_sale(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                FeeAssetBalance::Native(10),
+                CoreSeedBalance::get(),
+                6,
+                10
+            ),
+            Error::<Test>::TokenSaleAlreadyExists
+        );
+
+        // Not started yet.
+
+        assert_err!(
+            INV4::buy_tokens(RawOrigin::Signed(BOB).into(), 0u32, 1),
+            Error::<Test>::TokenSaleNotActive
+        );
+
+        System::set_block_number(6);
+
+        // Cap exceeded.
+
+        assert_err!(
+            INV4::buy_tokens(
                 RawOrigin::Signed(BOB).into(),
                 0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1),
-                true
+                CoreSeedBalance::get() + 1
             ),
-            Error::<Test>::MultisigCallNotFound
+            Error::<Test>::TokenSaleCapExceeded
+        );
+
+        // Not enough funds.
+
+        assert_err!(
+            INV4::buy_tokens(RawOrigin::Signed(DAVE).into(), 0u32, 1),
+            TokenError::FundsUnavailable
+        );
+
+        // Already ended.
+
+        System::set_block_number(11);
+
+        assert_err!(
+            INV4::buy_tokens(RawOrigin::Signed(BOB).into(), 0u32, 1),
+            Error::<Test>::TokenSaleNotActive
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+
+        // Costs overflowing the payment asset's balance type fail instead of saturating.
+
+        INV4::open_token_sale(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            FeeAssetBalance::Relay(Balance::MAX),
+            CoreSeedBalance::get(),
+            11,
+            20,
+        )
+        .unwrap();
+
+        assert_err!(
+            INV4::buy_tokens(RawOrigin::Signed(BOB).into(), 0u32, 2),
+            ArithmeticError::Overflow
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+    });
+}
+
+#[test]
+fn vesting_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false),
+            None,
+            None,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        // Linear schedule.
+
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1000,
+            BOB,
+            VestingSchedule::Linear { start: 1, end: 11 }
+        ));
+
+        System::assert_has_event(
+            Event::VestingScheduleAdded {
+                core_id: 0u32,
+                target: BOB,
+                vesting: Vesting {
+                    total: 1000,
+                    released: 0,
+                    schedule: VestingSchedule::Linear { start: 1, end: 11 },
+                },
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 1000u128);
+        assert_eq!(INV4::core_members(0u32, BOB), Some(()));
+        assert_eq!(INV4::locked_balance(0u32, &BOB), 1000u128);
+
+        // Cliff schedule.
+
+        assert_ok!(INV4::token_mint_vested(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            500,
+            BOB,
+            VestingSchedule::Cliff { unlock_at: 8 }
+        ));
+
+        assert_eq!(INV4::locked_balance(0u32, &BOB), 1500u128);
+
+        System::set_block_number(6);
+
+        assert_ok!(INV4::vest(RawOrigin::Signed(BOB).into(), 0u32));
+
+        System::assert_last_event(
+            Event::Vested {
+                core_id: 0u32,
+                target: BOB,
+                amount: 500,
+            }
+            .into(),
         );
+
+        assert_eq!(INV4::locked_balance(0u32, &BOB), 1000u128);
+
+        // Released tokens can be transferred.
+
+        assert_ok!(CoreAssets::transfer(
+            RawOrigin::Signed(BOB).into(),
+            ALICE,
+            0u32,
+            500
+        ));
+
+        System::set_block_number(8);
+
+        assert_ok!(INV4::vest(RawOrigin::Signed(BOB).into(), 0u32));
+
+        System::assert_last_event(
+            Event::Vested {
+                core_id: 0u32,
+                target: BOB,
+                amount: 700,
+            }
+            .into(),
+        );
+
+        // The cliff schedule is done and got removed.
+
+        assert_eq!(INV4::vesting_schedules(0u32, BOB).len(), 1);
+        assert_eq!(INV4::locked_balance(0u32, &BOB), 300u128);
+
+        System::set_block_number(20);
+
+        assert_ok!(INV4::vest(RawOrigin::Signed(BOB).into(), 0u32));
+
+        assert_eq!(INV4::vesting_schedules(0u32, BOB).len(), 0);
+        assert_eq!(INV4::locked_balance(0u32, &BOB), 0u128);
+
+        // Everything can be burned once released.
+
+        assert_ok!(INV4::token_burn(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1000,
+            BOB
+        ));
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+    });
+}
+
+#[test]
+fn vesting_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            Some(false),
+            None,
+            None,
+        )
+        .unwrap();
+
+        // Wrong origin.
+
+        assert_err!(
+            INV4::token_mint_vested(
+                RawOrigin::Signed(ALICE).into(),
+                1000,
+                BOB,
+                VestingSchedule::Cliff { unlock_at: 10 }
+            ),
+            BadOrigin
+        );
+
+        // Invalid schedules.
+
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                0,
+                BOB,
+                VestingSchedule::Cliff { unlock_at: 10 }
+            ),
+            Error::<Test>::InvalidVestingSchedule
+        );
+
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                1000,
+                BOB,
+                VestingSchedule::Linear { start: 10, end: 10 }
+            ),
+            Error::<Test>::InvalidVestingSchedule
+        );
+
+        // No schedules.
+
+        assert_err!(
+            INV4::vest(RawOrigin::Signed(BOB).into(), 0u32),
+            Error::<Test>::NoVestingSchedule
+        );
+
+        // Too many schedules.
+
+        for _ in 0..MaxVestingSchedules::get() {
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                100,
+                BOB,
+                VestingSchedule::Cliff { unlock_at: 10 },
+            )
+            .unwrap();
+        }
+
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                100,
+                BOB,
+                VestingSchedule::Cliff { unlock_at: 10 }
+            ),
+            Error::<Test>::MaxVestingSchedulesExceeded
+        );
+
+        // Locked tokens can't be transferred or burned.
+
+        assert_err!(
+            CoreAssets::transfer(RawOrigin::Signed(BOB).into(), ALICE, 0u32, 1),
+            Error::<Test>::InsufficientUnlockedBalance
+        );
+
+        assert_err!(
+            INV4::token_burn(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                1,
+                BOB
+            ),
+            Error::<Test>::InsufficientUnlockedBalance
+        );
+
+        // Nothing got released before the cliff.
+
+        System::set_block_number(5);
+
+        assert_ok!(INV4::vest(RawOrigin::Signed(BOB).into(), 0u32));
+
+        assert_eq!(
+            INV4::locked_balance(0u32, &BOB),
+            100 * MaxVestingSchedules::get() as u128
+        );
+
+        assert_eq!(
+            CoreAssets::accounts(BOB, 0u32).free,
+            100 * MaxVestingSchedules::get() as u128
+        );
+    });
+}
+
+#[test]
+fn max_supply_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        // Single holder meets the default unanimity requirement.
+
+        let call: RuntimeCall = pallet::Call::set_max_supply {
+            max_supply: Some(CoreSeedBalance::get() * 2),
+            supermajority: Some(Perbill::from_percent(67)),
+        }
+        .into();
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        System::assert_has_event(
+            Event::MaxSupplySet {
+                core_id: 0u32,
+                max_supply: Some(CoreSeedBalance::get() * 2),
+                supermajority: Perbill::from_percent(67),
+            }
+            .into(),
+        );
+
+        let core = INV4::core_storage(0u32).unwrap();
+
+        assert_eq!(core.max_supply, Some(CoreSeedBalance::get() * 2));
+        assert_eq!(core.max_supply_supermajority, Perbill::from_percent(67));
+
+        // Minting up to the cap.
+
+        assert_ok!(INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB
+        ));
+
+        assert_err!(
+            INV4::token_mint(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                1,
+                BOB
+            ),
+            Error::<Test>::MaxSupplyExceeded
+        );
+
+        // Half of the supply is not a supermajority anymore.
+
+        let call: RuntimeCall = pallet::Call::set_max_supply {
+            max_supply: None,
+            supermajority: None,
+        }
+        .into();
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: ALICE,
+                calls: vec![call.clone()],
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call),
+                failed_call: Some(0),
+                result: Err(Error::<Test>::SupermajorityNotReached.into()),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::core_storage(0u32).unwrap().max_supply,
+            Some(CoreSeedBalance::get() * 2)
+        );
+
+        // Removing the cap with enough support.
+
+        assert_ok!(INV4::set_max_supply(
+            Origin::Multisig(MultisigInternalOrigin::with_support(
+                0u32,
+                Perbill::from_percent(67)
+            ))
+            .into(),
+            None,
+            None
+        ));
+
+        assert_eq!(INV4::core_storage(0u32).unwrap().max_supply, None);
+
+        assert_ok!(INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1,
+            BOB
+        ));
+    });
+}
+
+#[test]
+fn max_supply_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        // Wrong origin.
+
+        assert_err!(
+            INV4::set_max_supply(
+                RawOrigin::Signed(ALICE).into(),
+                Some(CoreSeedBalance::get()),
+                None
+            ),
+            BadOrigin
+        );
+
+        // Not enough support.
+
+        assert_err!(
+            INV4::set_max_supply(
+                Origin::Multisig(MultisigInternalOrigin::with_support(
+                    0u32,
+                    Perbill::from_percent(99)
+                ))
+                .into(),
+                Some(CoreSeedBalance::get()),
+                None
+            ),
+            Error::<Test>::SupermajorityNotReached
+        );
+
+        // Cap below the current supply.
+
+        assert_err!(
+            INV4::set_max_supply(
+                Origin::Multisig(MultisigInternalOrigin::with_support(0u32, Perbill::one())).into(),
+                Some(CoreSeedBalance::get() - 1),
+                None
+            ),
+            Error::<Test>::MaxSupplyExceeded
+        );
+
+        assert_ok!(INV4::set_max_supply(
+            Origin::Multisig(MultisigInternalOrigin::with_support(0u32, Perbill::one())).into(),
+            Some(CoreSeedBalance::get()),
+            None
+        ));
+
+        // Every way of minting respects the cap.
+
+        assert_err!(
+            INV4::token_mint(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                1,
+                BOB
+            ),
+            Error::<Test>::MaxSupplyExceeded
+        );
+
+        assert_err!(
+            INV4::token_mint_vested(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                1,
+                BOB,
+                VestingSchedule::Cliff { unlock_at: 10 }
+            ),
+            Error::<Test>::MaxSupplyExceeded
+        );
+
+        INV4::invite_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1,
+            BOB,
+        )
+        .unwrap();
+
+        assert_err!(
+            INV4::accept_invitation(RawOrigin::Signed(BOB).into(), 0u32),
+            Error::<Test>::MaxSupplyExceeded
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+    });
+}
+
+#[test]
+fn minimum_voters_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(1),
+            Perbill::from_percent(1),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            CHARLIE,
+        )
+        .unwrap();
+
+        assert_eq!(INV4::core_member_count(0u32), 3);
+
+        // Absolute number of voters.
+
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(MinimumVoters::Absolute(2)))
+        ));
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // Caller has enough support but can't execute alone.
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        assert!(INV4::multisig(0u32, call_hash).is_some());
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0u128);
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true,
+            Weight::MAX
+        ));
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 1u128);
+        assert_eq!(INV4::core_member_count(0u32), 4);
+
+        // Share of the members.
+
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(MinimumVoters::Proportion(Perbill::from_percent(75))))
+        ));
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 2,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true,
+            Weight::MAX
+        ));
+
+        // 2 out of 4 members is not enough.
+
+        assert!(INV4::multisig(0u32, call_hash).is_some());
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            call_hash,
+            false,
+            Weight::MAX
+        ));
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 3u128);
+
+        // Removing the requirement allows instant execution again.
+
+        assert_ok!(INV4::set_parameters(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(None)
+        ));
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 5u128);
+    });
+}
+
+#[test]
+fn proposal_limits_work() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(50),
+            Perbill::from_percent(50),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1,
+            CHARLIE,
+        )
+        .unwrap();
+
+        let limits = ProposalLimits {
+            min_balance: CoreSeedBalance::get(),
+            max_open_proposals: 1,
+            cooldown: 5,
+            deposit: 1000,
+            slash_deposit: true,
+        };
+
+        // Wrong origin.
+
+        assert_err!(
+            INV4::set_proposal_limits(RawOrigin::Signed(ALICE).into(), Some(limits.clone())),
+            BadOrigin
+        );
+
+        assert_ok!(INV4::set_proposal_limits(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Some(limits.clone())
+        ));
+
+        System::assert_last_event(
+            Event::ProposalLimitsSet {
+                core_id: 0u32,
+                limits: Some(limits),
+            }
+            .into(),
+        );
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call1_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1);
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: 2,
+            target: DAVE,
+        }
+        .into();
+
+        let call2_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2);
+
+        // Balance too low.
+
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(CHARLIE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call1.clone()),
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::BalanceTooLowToPropose
+        );
+
+        // Deposit is reserved.
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        assert_eq!(Balances::reserved_balance(ALICE), 1000u128);
+        assert_eq!(INV4::open_proposals(0u32, ALICE), 1);
+        assert_eq!(INV4::proposal_deposits(0u32, call1_hash), Some(1000u128));
+
+        // Too many open proposals.
+
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call2.clone()),
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::TooManyOpenProposals
+        );
+
+        // Canceled proposals get the deposit slashed to the core account.
+
+        let core_balance = Balances::free_balance(INV4::derive_core_account(0u32));
+
+        assert_ok!(INV4::cancel_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            call1_hash
+        ));
+
+        System::assert_last_event(
+            Event::ProposalDepositSlashed {
+                core_id: 0u32,
+                call_hash: call1_hash,
+                proposer: ALICE,
+                amount: 1000,
+            }
+            .into(),
+        );
+
+        assert_eq!(Balances::reserved_balance(ALICE), 0u128);
+        assert_eq!(
+            Balances::free_balance(INV4::derive_core_account(0u32)),
+            core_balance + 1000
+        );
+        assert_eq!(INV4::open_proposals(0u32, ALICE), 0);
+        assert_eq!(INV4::proposal_deposits(0u32, call1_hash), None);
+
+        // The last proposal is kept while it's in its cooldown.
+        assert_eq!(INV4::last_proposal(0u32, ALICE), Some(1));
+
+        // Cooldown.
+
+        System::set_block_number(3);
+
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call2.clone()),
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::ProposalCooldown
+        );
+
+        System::set_block_number(6);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        assert_eq!(Balances::reserved_balance(ALICE), 1000u128);
+
+        // Executed proposals get the deposit back.
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call2_hash,
+            true,
+            Weight::MAX
+        ));
+
+        assert_eq!(INV4::multisig(0u32, call2_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 2u128);
+        assert_eq!(Balances::reserved_balance(ALICE), 0u128);
+        assert_eq!(INV4::open_proposals(0u32, ALICE), 0);
+        assert_eq!(INV4::proposal_deposits(0u32, call2_hash), None);
+
+        // The last proposal is removed once it's released after its cooldown.
+
+        System::set_block_number(11);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        assert_eq!(INV4::last_proposal(0u32, ALICE), Some(11));
+
+        System::set_block_number(16);
+
+        assert_ok!(INV4::cancel_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            call1_hash
+        ));
+
+        assert_eq!(INV4::last_proposal(0u32, ALICE), None);
+    });
+}
+
+#[test]
+fn operate_multisig_batch_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let calls: Vec<RuntimeCall> = vec![
+            pallet::Call::token_mint {
+                amount: CoreSeedBalance::get(),
+                target: BOB,
+            }
+            .into(),
+            pallet::Call::token_mint {
+                amount: CoreSeedBalance::get(),
+                target: CHARLIE,
+            }
+            .into(),
+        ];
+
+        assert_ok!(INV4::operate_multisig_batch(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            calls.clone().try_into().unwrap(),
+            vec![].try_into().unwrap()
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: ALICE,
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&calls),
+                calls: calls.clone(),
+                failed_call: None,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, CoreSeedBalance::get());
+        assert_eq!(
+            CoreAssets::accounts(CHARLIE, 0u32).free,
+            CoreSeedBalance::get()
+        );
+
+        System::set_block_number(2);
+
+        // Batches go through the voting stage like single calls.
+
+        let calls: Vec<RuntimeCall> = vec![
+            pallet::Call::token_mint {
+                amount: CoreSeedBalance::get(),
+                target: DAVE,
+            }
+            .into(),
+            pallet::Call::token_burn {
+                amount: CoreSeedBalance::get(),
+                target: CHARLIE,
+            }
+            .into(),
+        ];
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&calls);
+
+        assert_ok!(INV4::operate_multisig_batch(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            calls.clone().try_into().unwrap(),
+            vec![].try_into().unwrap()
+        ));
+
+        assert_eq!(
+            INV4::multisig(0u32, call_hash).map(|proposal| proposal.actual_call),
+            Some(BoundedCallBytes::<Test>::try_from(calls.encode()).unwrap())
+        );
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true,
+            Weight::MAX
+        ));
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            call_hash,
+            true,
+            Weight::MAX
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: CHARLIE,
+                call_hash,
+                calls,
+                failed_call: None,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            CoreAssets::accounts(DAVE, 0u32).free,
+            CoreSeedBalance::get()
+        );
+        assert_eq!(CoreAssets::accounts(CHARLIE, 0u32).free, 0u128);
+    });
+}
+
+#[test]
+fn operate_multisig_batch_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        assert_err!(
+            INV4::operate_multisig_batch(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                vec![].try_into().unwrap(),
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::EmptyBatch
+        );
+
+        // The second call fails, so the first one is reverted.
+
+        let calls: Vec<RuntimeCall> = vec![
+            pallet::Call::token_mint {
+                amount: CoreSeedBalance::get(),
+                target: BOB,
+            }
+            .into(),
+            pallet::Call::set_max_supply {
+                max_supply: Some(1),
+                supermajority: None,
+            }
+            .into(),
+            pallet::Call::token_mint {
+                amount: CoreSeedBalance::get(),
+                target: CHARLIE,
+            }
+            .into(),
+        ];
+
+        assert_ok!(INV4::operate_multisig_batch(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            calls.clone().try_into().unwrap(),
+            vec![].try_into().unwrap()
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: ALICE,
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&calls),
+                calls,
+                failed_call: Some(1),
+                result: Err(Error::<Test>::MaxSupplyExceeded.into()),
+            }
+            .into(),
+        );
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+        assert_eq!(CoreAssets::accounts(CHARLIE, 0u32).free, 0u128);
+        assert_eq!(INV4::core_storage(0u32).unwrap().max_supply, None);
+    });
+}
+
+#[test]
+fn multisig_call_weight_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: CHARLIE,
+        }
+        .into();
+        let call2_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2);
+
+        // Executed right away, the call's weight is included.
+
+        let post_info = INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            post_info.actual_weight,
+            Some(
+                <Test as Config>::WeightInfo::operate_multisig(
+                    0,
+                    vec![call1.clone()].using_encoded(|c| c.len() as u32)
+                )
+                .saturating_add(call1.get_dispatch_info().weight)
+            )
+        );
+
+        // Stored, no call is dispatched.
+
+        let post_info = INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<Test as Config>::WeightInfo::operate_multisig(
+                0,
+                vec![call2.clone()].using_encoded(|c| c.len() as u32)
+            ))
+        );
+
+        // The vote would execute the call, but its weight wasn't paid for.
+
+        assert_err!(
+            INV4::vote_multisig(
+                RawOrigin::Signed(BOB).into(),
+                0u32,
+                call2_hash,
+                true,
+                call2
+                    .get_dispatch_info()
+                    .weight
+                    .saturating_sub(Weight::from_parts(1, 0))
+            ),
+            Error::<Test>::MaxCallWeightExceeded
+        );
+
+        assert!(INV4::multisig(0u32, call2_hash).is_some());
+
+        let post_info = INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call2_hash,
+            true,
+            call2.get_dispatch_info().weight,
+        )
+        .unwrap();
+
+        assert_eq!(
+            post_info.actual_weight,
+            Some(
+                <Test as Config>::WeightInfo::vote_multisig()
+                    .saturating_add(call2.get_dispatch_info().weight)
+            )
+        );
+
+        assert_eq!(INV4::multisig(0u32, call2_hash), None);
+        assert_eq!(
+            CoreAssets::accounts(CHARLIE, 0u32).free,
+            CoreSeedBalance::get()
+        );
+    });
+}
+
+#[test]
+fn operate_multisig_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        // Test with single voter.
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![1, 2, 3].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        System::assert_has_event(
+            orml_tokens2::Event::Deposited {
+                currency_id: 0u32,
+                who: BOB,
+                amount: CoreSeedBalance::get(),
+            }
+            .into(),
+        );
+
+        System::assert_has_event(
+            Event::Minted {
+                core_id: 0u32,
+                target: BOB,
+                amount: CoreSeedBalance::get(),
+            }
+            .into(),
+        );
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: ALICE,
+                calls: vec![call.clone()],
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call),
+                failed_call: None,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        // Test with 2 voters, call should be stored for voting.
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            ),
+            None,
+        );
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![1, 2, 3].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap()
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteStarted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: ALICE,
+                votes_added: Vote::Aye(CoreSeedBalance::get()),
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: Some(vec![1, 2, 3].try_into().unwrap()),
+                tally: Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1),
+            })
+        );
+    });
+}
+
+#[test]
+fn operate_multisig_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        // Using this call now to add a second member to the multisig.
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        // Not a member of the multisig
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(CHARLIE).into(),
+                0u32,
+                Some(vec![1, 2, 3].try_into().unwrap()),
+                FeeAsset::Native,
+                Box::new(call.clone()),
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::NoPermission
+        );
+
+        // Max call length exceeded.
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(
+                    frame_system::pallet::Call::<Test>::remark {
+                        remark: vec![0u8; MAX_SIZE as usize]
+                    }
+                    .into()
+                ),
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::MaxCallLengthExceeded
+        );
+
+        // Multisig call already exists in storage.
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call.clone()),
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::MultisigCallAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn cancel_multisig_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![1, 2, 3].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::set_block_number(2);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![1, 2, 3].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: Some(vec![1, 2, 3].try_into().unwrap()),
+                tally: Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1),
+            })
+        );
+
+        assert_ok!(INV4::cancel_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+        ));
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            ),
+            None
+        );
+    });
+}
+
+#[test]
+fn cancel_multisig_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![1, 2, 3].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::set_block_number(2);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![1, 2, 3].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        // Wrong origin.
+        assert_err!(
+            INV4::cancel_multisig_proposal(
+                RawOrigin::Signed(ALICE).into(),
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            ),
+            BadOrigin
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: Some(vec![1, 2, 3].try_into().unwrap()),
+                tally: Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1),
+            })
+        );
+    });
+}
+
+#[test]
+fn multisig_dependencies_defer_execution() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: CHARLIE,
+        }
+        .into();
+        let call1_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1);
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: DAVE,
+        }
+        .into();
+        let call2_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![call1_hash].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(INV4::dependents(0u32, call1_hash), 1);
+
+        // The second proposal passes but waits for the first one.
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call2_hash,
+            true,
+            Weight::MAX
+        ));
+
+        System::assert_last_event(
+            Event::MultisigExecutionDeferred {
+                core_id: 0u32,
+                call_hash: call2_hash,
+            }
+            .into(),
+        );
+
+        assert!(INV4::multisig(0u32, call2_hash).is_some());
+
+        assert_err!(
+            INV4::execute_multisig(
+                RawOrigin::Signed(CHARLIE).into(),
+                0u32,
+                call2_hash,
+                Weight::MAX
+            ),
+            Error::<Test>::DependenciesPending
+        );
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call1_hash,
+            true,
+            Weight::MAX
+        ));
+
+        assert_eq!(INV4::proposal_results(0u32, call1_hash), Some(true));
+
+        assert_ok!(INV4::execute_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            call2_hash,
+            Weight::MAX
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: CHARLIE,
+                call_hash: call2_hash,
+                calls: vec![call2],
+                failed_call: None,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            CoreAssets::accounts(DAVE, 0u32).free,
+            CoreSeedBalance::get()
+        );
+        assert_eq!(INV4::multisig(0u32, call2_hash), None);
+        assert_eq!(INV4::dependents(0u32, call1_hash), 0);
+        assert_eq!(INV4::proposal_results(0u32, call1_hash), None);
+    });
+}
+
+#[test]
+fn multisig_dependencies_fail() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(51),
+            Perbill::from_percent(51),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: CHARLIE,
+        }
+        .into();
+        let call1_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1);
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: DAVE,
+        }
+        .into();
+        let call2_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2);
+
+        let call3: RuntimeCall = pallet::Call::token_burn {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        // Depending on an unknown proposal.
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call2.clone()),
+                vec![call1_hash].try_into().unwrap(),
+            ),
+            Error::<Test>::DependencyNotFound
+        );
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![call1_hash].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_ok!(INV4::cancel_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            call1_hash
+        ));
+
+        assert_eq!(INV4::proposal_results(0u32, call1_hash), Some(false));
+
+        System::assert_has_event(
+            Event::MultisigDependentsFailed {
+                core_id: 0u32,
+                call_hash: call1_hash,
+                dependents: 1,
+            }
+            .into(),
+        );
+
+        // New proposals can't depend on a failed one.
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call3.clone()),
+                vec![call1_hash].try_into().unwrap(),
+            ),
+            Error::<Test>::DependencyFailed
+        );
+
+        assert_ok!(INV4::execute_multisig(
+            RawOrigin::Signed(CHARLIE).into(),
+            0u32,
+            call2_hash,
+            Weight::MAX
+        ));
+
+        System::assert_last_event(
+            Event::MultisigDependencyFailed {
+                core_id: 0u32,
+                call_hash: call2_hash,
+                dependency: call1_hash,
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::multisig(0u32, call2_hash), None);
+        assert_eq!(CoreAssets::accounts(DAVE, 0u32).free, 0u128);
+        assert_eq!(INV4::proposal_results(0u32, call1_hash), None);
+    });
+}
+
+#[test]
+fn amend_multisig_metadata_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(vec![1, 2, 3].try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            false,
+            Weight::MAX,
+        )
+        .unwrap();
+
+        let balance = Balances::free_balance(ALICE);
+        let call_len = call.encode().len();
+
+        // Longer metadata is charged for the extra storage.
+
+        assert_ok!(INV4::amend_multisig_metadata(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            call_hash,
+            Some(vec![1; 10].try_into().unwrap())
+        ));
+
+        System::assert_last_event(
+            Event::MultisigMetadataAmended {
+                core_id: 0u32,
+                call_hash,
+                metadata: Some(vec![1; 10]),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            Balances::free_balance(ALICE),
+            balance - INV4::proposal_storage_cost(call_len, 10)
+                + INV4::proposal_storage_cost(call_len, 3)
+        );
+
+        // Votes are kept.
+
+        assert_eq!(
+            INV4::multisig(0u32, call_hash),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: Some(vec![1; 10].try_into().unwrap()),
+                tally: Tally::from_parts(CoreSeedBalance::get(), CoreSeedBalance::get(), 2),
+            })
+        );
+
+        // Removing the metadata is not refunded.
+
+        let balance = Balances::free_balance(ALICE);
+
+        assert_ok!(INV4::amend_multisig_metadata(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            call_hash,
+            None
+        ));
+
+        assert_eq!(Balances::free_balance(ALICE), balance);
+        assert_eq!(INV4::multisig(0u32, call_hash).unwrap().metadata, None);
+    });
+}
+
+#[test]
+fn amend_multisig_metadata_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        // Proposal doesn't exist.
+
+        assert_err!(
+            INV4::amend_multisig_metadata(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                call_hash,
+                Some(vec![1, 2, 3].try_into().unwrap())
+            ),
+            Error::<Test>::MultisigCallNotFound
+        );
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        // Not the original caller.
+
+        assert_err!(
+            INV4::amend_multisig_metadata(
+                RawOrigin::Signed(BOB).into(),
+                0u32,
+                call_hash,
+                Some(vec![1, 2, 3].try_into().unwrap())
+            ),
+            Error::<Test>::NotOriginalCaller
+        );
+
+        assert_eq!(INV4::multisig(0u32, call_hash).unwrap().metadata, None);
+    });
+}
+
+#[test]
+fn vote_multisig_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: CHARLIE,
+        }
+        .into();
+
+        // Adding BOB.
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::set_block_number(2);
+
+        // Adding CHARLIE
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call2.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1),
+            })
+        );
+
+        // BOB votes nay.
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            false,
+            Weight::MAX
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteAdded {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: BOB,
+                votes_added: Vote::Nay(CoreSeedBalance::get()),
+                current_votes: Tally::from_parts(CoreSeedBalance::get(), CoreSeedBalance::get(), 2),
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call2.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(CoreSeedBalance::get(), CoreSeedBalance::get(), 2),
+            })
+        );
+
+        // BOB changes vote to aye, executing the call.
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            true,
+            Weight::MAX
+        ));
+
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: BOB,
+                calls: vec![call2.clone()],
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+                failed_call: None,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            None
+        );
+    });
+}
+
+#[test]
+fn vote_multisig_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: CHARLIE,
+        }
+        .into();
+
+        // Adding BOB.
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::set_block_number(2);
+
+        // Adding CHARLIE
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call2.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1),
+            })
+        );
+
+        // Not a member of the multisig.
+        assert_err!(
+            INV4::vote_multisig(
+                RawOrigin::Signed(DAVE).into(),
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+                true,
+                Weight::MAX
+            ),
+            Error::<Test>::NoPermission
+        );
+
+        // Call not found.
+        assert_err!(
+            INV4::vote_multisig(
+                RawOrigin::Signed(BOB).into(),
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1),
+                true,
+                Weight::MAX
+            ),
+            Error::<Test>::MultisigCallNotFound
+        );
+    });
+}
+
+#[test]
+fn withdraw_vote_multisig_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: CHARLIE,
+        }
+        .into();
+
+        // Adding BOB.
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::set_block_number(2);
+
+        // Adding CHARLIE
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        // BOB votes nay.
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            false,
+            Weight::MAX
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteAdded {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: BOB,
+                votes_added: Vote::Nay(CoreSeedBalance::get()),
+                current_votes: Tally::from_parts(CoreSeedBalance::get(), CoreSeedBalance::get(), 2),
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call2.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(CoreSeedBalance::get(), CoreSeedBalance::get(), 2),
+            })
+        );
+
+        // BOB withdraws his vote.
+
+        assert_ok!(INV4::withdraw_vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteWithdrawn {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: BOB,
+                votes_removed: Vote::Nay(CoreSeedBalance::get()),
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call2.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1),
+            })
+        );
+
+        // ALICE also withdraws her vote.
+
+        assert_ok!(INV4::withdraw_vote_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+        ));
+
+        System::assert_has_event(
+            Event::MultisigVoteWithdrawn {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: ALICE,
+                votes_removed: Vote::Aye(CoreSeedBalance::get()),
+                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call2.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(Zero::zero(), Zero::zero(), 0),
+            })
+        );
+    });
+}
+
+#[test]
+fn withdraw_vote_multisig_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let call2: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: CHARLIE,
+        }
+        .into();
+
+        // Adding BOB.
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::set_block_number(2);
+
+        // Adding CHARLIE
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call2.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        // BOB votes nay.
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            false,
+            Weight::MAX
+        ));
+
+        // Multisig call not found.
+        assert_err!(
+            INV4::withdraw_vote_multisig(
+                RawOrigin::Signed(BOB).into(),
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1),
+            ),
+            Error::<Test>::MultisigCallNotFound
+        );
+
+        // Not a voter in this proposal.
+        assert_err!(
+            INV4::withdraw_vote_multisig(
+                RawOrigin::Signed(CHARLIE).into(),
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            ),
+            Error::<Test>::NotAVoter
+        );
+
+        assert_eq!(
+            INV4::multisig(
+                0u32,
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            ),
+            Some(MultisigOperation {
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call2.clone()].encode())
+                    .unwrap(),
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+                original_caller: ALICE,
+                metadata: None,
+                tally: Tally::from_parts(CoreSeedBalance::get(), CoreSeedBalance::get(), 2),
+            })
+        );
+    });
+}
+
+#[test]
+fn clear_multisig_votes_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            CHARLIE,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: DAVE,
+        }
+        .into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            false,
+            Weight::MAX
+        ));
+
+        assert_eq!(
+            INV4::multisig_votes((0u32, call_hash, BOB)),
+            Some(Vote::Nay(CoreSeedBalance::get()))
+        );
+
+        // Votes are needed while the proposal is ongoing.
+
+        assert_err!(
+            INV4::clear_multisig_votes(RawOrigin::Signed(DAVE).into(), 0u32, call_hash, 10),
+            Error::<Test>::MultisigStillOngoing
+        );
+
+        assert_ok!(INV4::cancel_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            call_hash
+        ));
+
+        // Canceling the proposal clears its votes.
+
+        assert_eq!(INV4::multisig_votes((0u32, call_hash, ALICE)), None);
+        assert_eq!(INV4::multisig_votes((0u32, call_hash, BOB)), None);
+
+        // Votes past the amount cleared along with the proposal are left behind.
+
+        MultisigVotes::<Test>::insert((0u32, call_hash, ALICE), Vote::Aye(CoreSeedBalance::get()));
+        MultisigVotes::<Test>::insert((0u32, call_hash, BOB), Vote::Nay(CoreSeedBalance::get()));
+
+        // The same call can't be proposed again until the votes are cleared.
+
+        assert_err!(
+            INV4::operate_multisig(
+                RawOrigin::Signed(ALICE).into(),
+                0u32,
+                None,
+                FeeAsset::Native,
+                Box::new(call.clone()),
+                vec![].try_into().unwrap(),
+            ),
+            Error::<Test>::VotesNotCleared
+        );
+
+        assert_ok!(INV4::clear_multisig_votes(
+            RawOrigin::Signed(DAVE).into(),
+            0u32,
+            call_hash,
+            1
+        ));
+
+        System::assert_last_event(
+            Event::MultisigVotesCleared {
+                core_id: 0u32,
+                call_hash,
+                cleared: 1,
+                complete: false,
+            }
+            .into(),
+        );
+
+        assert_ok!(INV4::clear_multisig_votes(
+            RawOrigin::Signed(DAVE).into(),
+            0u32,
+            call_hash,
+            10
+        ));
+
+        System::assert_last_event(
+            Event::MultisigVotesCleared {
+                core_id: 0u32,
+                call_hash,
+                cleared: 1,
+                complete: true,
+            }
+            .into(),
+        );
+
+        assert_eq!(INV4::multisig_votes((0u32, call_hash, ALICE)), None);
+        assert_eq!(INV4::multisig_votes((0u32, call_hash, BOB)), None);
+
+        assert_ok!(INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        ));
+
+        assert_eq!(
+            INV4::multisig(0u32, call_hash).map(|proposal| proposal.tally),
+            Some(Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1))
+        );
+    });
+}
+
+#[test]
+fn proposal_archive_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        assert_eq!(INV4::archived_proposals(0u32), vec![]);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: DAVE,
+        }
+        .into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        // Proposals are only archived once resolved.
+
+        assert_eq!(INV4::archived_proposals(0u32), vec![]);
+
+        System::set_block_number(2);
+
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true,
+            Weight::MAX
+        ));
+
+        let canceled_call: RuntimeCall = pallet::Call::token_burn {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+        let canceled_call_hash =
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&canceled_call);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            None,
+            FeeAsset::Native,
+            Box::new(canceled_call),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::set_block_number(3);
+
+        assert_ok!(INV4::cancel_multisig_proposal(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            canceled_call_hash
+        ));
+
+        assert_eq!(INV4::proposal_archive_count(0u32), 2);
+
+        assert_eq!(
+            INV4::archived_proposals(0u32),
+            vec![
+                ArchivedProposal {
+                    call_hash,
+                    proposer: ALICE,
+                    ayes: CoreSeedBalance::get() * 2,
+                    nays: Zero::zero(),
+                    voters: 2,
+                    outcome: ProposalOutcome::Executed,
+                    result: Some(Ok(())),
+                    resolved_at: 2,
+                },
+                ArchivedProposal {
+                    call_hash: canceled_call_hash,
+                    proposer: BOB,
+                    ayes: CoreSeedBalance::get(),
+                    nays: Zero::zero(),
+                    voters: 1,
+                    outcome: ProposalOutcome::Canceled,
+                    result: None,
+                    resolved_at: 3,
+                }
+            ]
+        );
+    });
+}
+
+#[test]
+fn proposal_archive_wraps_around() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let max_archived = <Test as Config>::MaxArchivedProposals::get();
+
+        // ALICE is the only member, so every proposal is executed right away.
+        let call_hashes = (1..=max_archived + 2)
+            .map(|amount| {
+                let call: RuntimeCall = pallet::Call::token_mint {
+                    amount: amount.into(),
+                    target: DAVE,
+                }
+                .into();
+
+                assert_ok!(INV4::operate_multisig(
+                    RawOrigin::Signed(ALICE).into(),
+                    0u32,
+                    None,
+                    FeeAsset::Native,
+                    Box::new(call.clone()),
+                    vec![].try_into().unwrap(),
+                ));
+
+                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            INV4::proposal_archive_count(0u32),
+            (max_archived + 2) as u64
+        );
+
+        let archived = INV4::archived_proposals(0u32);
+
+        // The two oldest proposals were overwritten.
+        assert_eq!(
+            archived
+                .iter()
+                .map(|proposal| proposal.call_hash)
+                .collect::<Vec<_>>(),
+            call_hashes[2..]
+        );
+
+        assert!(archived.iter().all(|proposal| proposal.proposer == ALICE
+            && proposal.outcome == ProposalOutcome::Executed
+            && proposal.result == Some(Ok(()))));
+
+        // Other cores have their own archive.
+        assert_eq!(INV4::archived_proposals(1u32), vec![]);
+    });
+}
+
+#[test]
+fn runtime_api_queries_work() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            test_metadata(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        INV4::token_mint(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            CoreSeedBalance::get(),
+            BOB,
+        )
+        .unwrap();
+
+        INV4::create_core(
+            RawOrigin::Signed(BOB).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let core_info = INV4::core_info(0u32).unwrap();
+        let stored_core_info = INV4::core_storage(0u32).unwrap();
+
+        assert_eq!(core_info.account, stored_core_info.account);
+        assert_eq!(core_info.metadata.name, b"Test core".to_vec());
+        assert_eq!(core_info.metadata.website, b"https://test.core".to_vec());
+        assert_eq!(
+            core_info.metadata.content_hash,
+            stored_core_info.metadata.content_hash
+        );
+        assert_eq!(core_info.minimum_support, Perbill::from_percent(100));
+        assert_eq!(core_info.required_approval, Perbill::from_percent(100));
+        assert_eq!(core_info.frozen_tokens, stored_core_info.frozen_tokens);
+
+        assert_eq!(INV4::core_info(2u32), None);
+
+        let mut members = INV4::members_of(0u32);
+        members.sort();
+        assert_eq!(members, vec![ALICE, BOB]);
+
+        assert_eq!(INV4::members_of(1u32), vec![BOB]);
+        assert_eq!(INV4::members_of(2u32), vec![]);
+
+        assert_eq!(INV4::cores_of(ALICE), vec![0u32]);
+
+        let mut bob_cores = INV4::cores_of(BOB);
+        bob_cores.sort();
+        assert_eq!(bob_cores, vec![0u32, 1u32]);
+
+        assert_eq!(INV4::cores_of(DAVE), vec![]);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: DAVE,
+        }
+        .into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call);
+
+        assert_eq!(INV4::pending_proposals(0u32), vec![]);
+
+        INV4::operate_multisig(
+            RawOrigin::Signed(ALICE).into(),
+            0u32,
+            Some(b"proposal".to_vec().try_into().unwrap()),
+            FeeAsset::Native,
+            Box::new(call.clone()),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            INV4::pending_proposals(0u32),
+            vec![PendingProposal {
+                call_hash,
+                proposer: ALICE,
+                calls: Some(vec![call]),
+                ayes: CoreSeedBalance::get(),
+                nays: Zero::zero(),
+                voters: 1,
+                metadata: Some(b"proposal".to_vec()),
+                fee_asset: FeeAsset::Native,
+                depends_on: vec![],
+            }]
+        );
+
+        assert_eq!(INV4::pending_proposals(1u32), vec![]);
+
+        // BOB voting aye would get the proposal to 100% support.
+        assert!(INV4::vote_would_execute(0u32, call_hash, BOB, true));
+        assert!(!INV4::vote_would_execute(0u32, call_hash, BOB, false));
+
+        // ALICE replacing her vote doesn't add support, and DAVE has no voting power.
+        assert!(!INV4::vote_would_execute(0u32, call_hash, ALICE, true));
+        assert!(!INV4::vote_would_execute(0u32, call_hash, DAVE, true));
+
+        // Unknown proposals can't be executed.
+        assert!(!INV4::vote_would_execute(
+            0u32,
+            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&0u32),
+            BOB,
+            true
+        ));
+
+        // The query matches what actually happens.
+        assert_ok!(INV4::vote_multisig(
+            RawOrigin::Signed(BOB).into(),
+            0u32,
+            call_hash,
+            true,
+            Weight::MAX
+        ));
+
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
+        assert_eq!(INV4::pending_proposals(0u32), vec![]);
+
+        let mut members = INV4::members_of(0u32);
+        members.sort();
+        assert_eq!(members, vec![ALICE, BOB, DAVE]);
+        assert_eq!(INV4::cores_of(DAVE), vec![0u32]);
+    });
+}
+
+#[test]
+fn migrate_to_v3_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_ok!(<CoreAssets as fungibles::Mutate<_>>::mint_into(
+            0u32, &ALICE, 100
+        ));
+        assert_ok!(<CoreAssets as fungibles::Mutate<_>>::mint_into(
+            0u32, &BOB, 50
+        ));
+
+        // MemberCores and CoreMemberCount didn't exist in v2.
+        let _ = MemberCores::<Test>::clear(u32::MAX, None);
+        let _ = CoreMemberCount::<Test>::clear(u32::MAX, None);
+
+        migrations::v3::CoreStorage::<Test>::insert(
+            0u32,
+            migrations::v3::OldCoreInfo {
+                account: INV4::derive_core_account(0u32),
+                metadata: b"Old metadata".to_vec(),
+                minimum_support: Perbill::from_percent(50),
+                required_approval: Perbill::from_percent(60),
+                frozen_tokens: true,
+            },
+        );
+        CoreByAccount::<Test>::insert(INV4::derive_core_account(0u32), 0u32);
+
+        let call1: RuntimeCall = pallet::Call::token_mint {
+            amount: 1,
+            target: DAVE,
+        }
+        .into();
+
+        let call1_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1);
+
+        let call2_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&2u32);
+
+        migrations::v3::Multisig::<Test>::insert(
+            0u32,
+            call1_hash,
+            migrations::v3::OldMultisigOperation {
+                tally: migrations::v3::OldTally {
+                    ayes: 100,
+                    nays: 50,
+                    records: [(ALICE, Vote::Aye(100)), (BOB, Vote::Nay(50))]
+                        .into_iter()
+                        .collect(),
+                },
+                original_caller: ALICE,
+                actual_call: call1.encode(),
+                metadata: None,
+                fee_asset: FeeAsset::Native,
+            },
+        );
+
+        // A call filling the whole call size doesn't fit once wrapped in a batch.
+        migrations::v3::Multisig::<Test>::insert(
+            0u32,
+            call2_hash,
+            migrations::v3::OldMultisigOperation {
+                tally: migrations::v3::OldTally {
+                    ayes: 100,
+                    nays: 0,
+                    records: [(ALICE, Vote::Aye(100))].into_iter().collect(),
+                },
+                original_caller: ALICE,
+                actual_call: vec![0u8; MAX_SIZE as usize],
+                metadata: None,
+                fee_asset: FeeAsset::Native,
+            },
+        );
+
+        StorageVersion::new(2).put::<INV4>();
+
+        migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(INV4::on_chain_storage_version(), 3);
+
+        assert_eq!(
+            INV4::core_storage(0u32),
+            Some(CoreInfo {
+                account: INV4::derive_core_account(0u32),
+                metadata: CoreMetadata {
+                    description: b"Old metadata".to_vec().try_into().unwrap(),
+                    ..Default::default()
+                },
+                minimum_support: Perbill::from_percent(50),
+                required_approval: Perbill::from_percent(60),
+                minimum_voters: None,
+                frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
+            })
+        );
+
+        assert_eq!(
+            INV4::multisig(0u32, call1_hash),
+            Some(MultisigOperation {
+                tally: Tally::from_parts(100, 50, 2),
+                original_caller: ALICE,
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![call1].encode()).unwrap(),
+                metadata: None,
+                fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
+            })
+        );
+        assert_eq!(
+            INV4::multisig_votes((0u32, call1_hash, ALICE)),
+            Some(Vote::Aye(100))
+        );
+        assert_eq!(
+            INV4::multisig_votes((0u32, call1_hash, BOB)),
+            Some(Vote::Nay(50))
+        );
+
+        assert_eq!(INV4::multisig(0u32, call2_hash), None);
+        assert_eq!(INV4::multisig_votes((0u32, call2_hash, ALICE)), None);
+
+        assert_eq!(INV4::member_cores(ALICE, 0u32), Some(()));
+        assert_eq!(INV4::member_cores(BOB, 0u32), Some(()));
+        assert_eq!(INV4::core_member_count(0u32), 2);
+
+        // Running it again is a no-op.
+
+        migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(INV4::core_member_count(0u32), 2);
+    });
+}
+
+#[test]
+fn dry_run_call_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let call: RuntimeCall = pallet::Call::token_mint {
+            amount: CoreSeedBalance::get(),
+            target: BOB,
+        }
+        .into();
+
+        let event_count = System::event_count();
+
+        let dry_run = INV4::dry_run_call(0u32, call.clone(), FeeAsset::Native).unwrap();
+
+        assert_eq!(dry_run.result, Ok(()));
+        assert_eq!(dry_run.actual_weight, call.get_dispatch_info().weight);
+        assert_eq!(dry_run.fee, Some(FeeAssetBalance::Native(0u128)));
+        assert!(dry_run.events.contains(
+            &Event::Minted {
+                core_id: 0u32,
+                target: BOB,
+                amount: CoreSeedBalance::get(),
+            }
+            .into()
+        ));
+
+        // Nothing was actually dispatched.
+
+        assert_eq!(CoreAssets::accounts(BOB, 0u32).free, 0u128);
+        assert_eq!(INV4::core_members(0u32, BOB), None);
+        assert_eq!(System::event_count(), event_count);
+
+        // The core has no balance to transfer.
+
+        let dry_run = INV4::dry_run_call(
+            0u32,
+            pallet_balances::Call::transfer_keep_alive {
+                dest: BOB,
+                value: INITIAL_BALANCE,
+            }
+            .into(),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        assert!(dry_run.result.is_err());
+        assert_eq!(dry_run.events, vec![]);
+
+        assert_err!(
+            INV4::dry_run_call(1u32, call, FeeAsset::Native),
+            Error::<Test>::CoreNotFound
+        );
+    });
+}
+
+#[test]
+fn ensure_origin_adapters_work() {
+    ExtBuilder::default().build_and_execute(|| {
+        let core_origin = |core_id: u32| -> RuntimeOrigin {
+            Origin::Multisig(MultisigInternalOrigin::new(core_id)).into()
+        };
+
+        assert_eq!(
+            EnsureCore::<Test>::try_origin(core_origin(0u32)).ok(),
+            Some(0u32)
+        );
+        assert_eq!(
+            EnsureCore::<Test>::try_origin(core_origin(1u32)).ok(),
+            Some(1u32)
+        );
+        assert!(EnsureCore::<Test>::try_origin(RuntimeOrigin::signed(ALICE)).is_err());
+        assert!(EnsureCore::<Test>::try_origin(RuntimeOrigin::root()).is_err());
+
+        assert_eq!(
+            EnsureCoreId::<Test, ConstU32<1>>::try_origin(core_origin(1u32)).ok(),
+            Some(())
+        );
+        assert!(EnsureCoreId::<Test, ConstU32<1>>::try_origin(core_origin(0u32)).is_err());
+        assert!(
+            EnsureCoreId::<Test, ConstU32<1>>::try_origin(RuntimeOrigin::signed(ALICE)).is_err()
+        );
+
+        assert_eq!(
+            EnsureCoreAccount::<Test>::try_origin(core_origin(0u32)).ok(),
+            Some(INV4::derive_core_account(0u32))
+        );
+        assert!(EnsureCoreAccount::<Test>::try_origin(RuntimeOrigin::signed(ALICE)).is_err());
+
+        // Rejected origins are handed back untouched.
+        assert_eq!(
+            EnsureCoreId::<Test, ConstU32<1>>::try_origin(core_origin(0u32))
+                .err()
+                .and_then(|o| EnsureCore::<Test>::try_origin(o).ok()),
+            Some(0u32)
+        );
+    });
+}
+
+#[test]
+fn as_derivative_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        System::set_block_number(1);
+
+        let derivative = INV4::lookup_derivative(0u32, 1).unwrap();
+
+        assert_eq!(
+            derivative,
+            <Pallet<Test> as CoreAccountDerivation<Test>>::derive_core_derivative_account(0u32, 1)
+        );
+        assert_ne!(derivative, INV4::derive_core_account(0u32));
+        assert_ne!(derivative, INV4::lookup_derivative(0u32, 0).unwrap());
+
+        // Unknown cores have no sub-accounts.
+        assert_eq!(INV4::lookup_derivative(1u32, 1), None);
+
+        let amount = EXISTENTIAL_DEPOSIT * 10;
+
+        Balances::transfer_keep_alive(RawOrigin::Signed(ALICE).into(), derivative.clone(), amount)
+            .unwrap();
+
+        let call: RuntimeCall = pallet_balances::Call::transfer_allow_death {
+            dest: DAVE,
+            value: amount,
+        }
+        .into();
+
+        assert_ok!(INV4::as_derivative(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1,
+            Box::new(call.clone()),
+        ));
+
+        System::assert_last_event(
+            Event::DerivativeDispatched {
+                core_id: 0u32,
+                index: 1,
+                account: derivative.clone(),
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(Balances::free_balance(DAVE), amount);
+        assert_eq!(Balances::free_balance(derivative), 0u128);
+
+        // The sub-account has nothing left to transfer, the failure is reported in the event.
+        assert_ok!(INV4::as_derivative(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1,
+            Box::new(call.clone()),
+        ));
+
+        assert!(matches!(
+            System::events().last().map(|record| &record.event),
+            Some(RuntimeEvent::INV4(Event::DerivativeDispatched {
+                core_id: 0u32,
+                index: 1,
+                result: Err(_),
+                ..
+            }))
+        ));
+
+        assert_err!(
+            INV4::as_derivative(RawOrigin::Signed(ALICE).into(), 1, Box::new(call)),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn core_location_conversion_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let core_location = MultiLocation {
+            parents: 1,
+            interior: INV4::core_location(0u32),
+        };
+        let derivative_location = MultiLocation {
+            parents: 1,
+            interior: INV4::core_derivative_location(0u32, 1),
+        };
+        let local_core_location = MultiLocation {
+            parents: 0,
+            interior: X1(Plurality {
+                id: BodyId::Index(0),
+                part: BodyPart::Voice,
+            }),
+        };
+        let sibling_core_location = MultiLocation {
+            parents: 1,
+            interior: X2(
+                Parachain(2),
+                Plurality {
+                    id: BodyId::Index(0),
+                    part: BodyPart::Voice,
+                },
+            ),
+        };
+
+        // Locations are converted into the same accounts derived locally.
+        assert_eq!(
+            CoreLocationToAccountId::<Test>::convert_location(&core_location),
+            Some(INV4::derive_core_account(0u32))
+        );
+        assert_eq!(
+            CoreLocationToAccountId::<Test>::convert_location(&derivative_location),
+            Some(INV4::derive_core_derivative_account(0u32, 1))
+        );
+        assert_eq!(
+            CoreLocationToAccountId::<Test>::convert_location(&local_core_location),
+            Some(INV4::derive_core_account(0u32))
+        );
+
+        // Cores of other chains get the account derived in their own chain.
+        assert_eq!(
+            CoreLocationToAccountId::<Test>::convert_location(&sibling_core_location),
+            Some(account_derivation::core_account_hash(2, 0).into())
+        );
+
+        assert_eq!(
+            CoreLocationToAccountId::<Test>::convert_location(&MultiLocation {
+                parents: 1,
+                interior: X1(Parachain(2)),
+            }),
+            None
+        );
+        assert_eq!(
+            CoreLocationToAccountId::<Test>::convert_location(&MultiLocation {
+                parents: 1,
+                interior: X2(
+                    Parachain(2125),
+                    Plurality {
+                        id: BodyId::Index(0),
+                        part: BodyPart::Members,
+                    },
+                ),
+            }),
+            None
+        );
+
+        // Only native origins of this chain's cores become core origins.
+        let origin = <CoreOriginConverter<Test, RuntimeOrigin> as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+            core_location,
+            OriginKind::Native,
+        )
+        .unwrap();
+        assert_eq!(
+            crate::origin::ensure_multisig::<Test, RuntimeOrigin>(origin).unwrap(),
+            MultisigInternalOrigin::new(0u32)
+        );
+
+        let origin = <CoreOriginConverter<Test, RuntimeOrigin> as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+            local_core_location,
+            OriginKind::Native,
+        )
+        .unwrap();
+        assert_eq!(
+            crate::origin::ensure_multisig::<Test, RuntimeOrigin>(origin).unwrap(),
+            MultisigInternalOrigin::new(0u32)
+        );
+
+        assert!(<CoreOriginConverter<Test, RuntimeOrigin> as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+            core_location,
+            OriginKind::SovereignAccount,
+        )
+        .is_err());
+        assert!(<CoreOriginConverter<Test, RuntimeOrigin> as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+            sibling_core_location,
+            OriginKind::Native,
+        )
+        .is_err());
+        assert!(<CoreOriginConverter<Test, RuntimeOrigin> as ConvertOrigin<RuntimeOrigin>>::convert_origin(
+            derivative_location,
+            OriginKind::Native,
+        )
+        .is_err());
+    });
+}
+
+#[test]
+fn xcm_core_origin_works() {
+    MockNet::reset();
+
+    let core_body = X1(Plurality {
+        id: BodyId::Index(0),
+        part: BodyPart::Voice,
+    });
+
+    let call: RuntimeCall = pallet::Call::as_derivative {
+        index: 1,
+        call: Box::new(frame_system::Call::remark_with_event { remark: vec![1] }.into()),
+    }
+    .into();
+    let weight = call.get_dispatch_info().weight;
+
+    ParaA::execute_with(|| {
+        INV4::create_core(
+            RawOrigin::Signed(ALICE).into(),
+            Default::default(),
+            Perbill::from_percent(100),
+            Perbill::from_percent(100),
+            FeeAsset::Native,
+        )
+        .unwrap();
+
+        assert_ok!(send_xcm::<ParachainXcmRouter<MsgQueue>>(
+            (Parent, Parachain(xcm_mock::PARA_B_ID)).into(),
+            Xcm(vec![
+                DescendOrigin(core_body),
+                Transact {
+                    origin_kind: OriginKind::Native,
+                    require_weight_at_most: weight,
+                    call: call.encode().into(),
+                },
+            ]),
+        ));
+    });
+
+    // The core acts as itself in the sibling chain.
+    ParaB::execute_with(|| {
+        System::assert_has_event(
+            Event::DerivativeDispatched {
+                core_id: 0u32,
+                index: 1,
+                account: INV4::derive_core_derivative_account(0u32, 1),
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+
+    let amount = EXISTENTIAL_DEPOSIT * 10;
+
+    let call: RuntimeCall = pallet_balances::Call::transfer_allow_death {
+        dest: DAVE,
+        value: amount,
+    }
+    .into();
+    let weight = call.get_dispatch_info().weight;
+
+    ParaA::execute_with(|| {
+        assert_ok!(send_xcm::<ParachainXcmRouter<MsgQueue>>(
+            (Parent, Parachain(xcm_mock::PARA_B_ID)).into(),
+            Xcm(vec![
+                DescendOrigin(core_body),
+                Transact {
+                    origin_kind: OriginKind::SovereignAccount,
+                    require_weight_at_most: weight,
+                    call: call.encode().into(),
+                },
+            ]),
+        ));
+    });
+
+    // The core's account in the sibling chain is the same as the local one.
+    ParaB::execute_with(|| {
+        assert_eq!(Balances::free_balance(DAVE), amount);
+        assert_eq!(
+            Balances::free_balance(INV4::derive_core_account(0u32)),
+            INITIAL_BALANCE - amount
+        );
+    });
+
+    ParaA::execute_with(|| {
+        assert_eq!(Balances::free_balance(DAVE), 0u128);
+    });
+
+    // Cores of chains other than the pallet's ParaId don't get a core origin.
+    let call: RuntimeCall = pallet::Call::as_derivative {
+        index: 1,
+        call: Box::new(frame_system::Call::remark_with_event { remark: vec![1] }.into()),
+    }
+    .into();
+    let weight = call.get_dispatch_info().weight;
+
+    ParaB::execute_with(|| {
+        assert_ok!(send_xcm::<ParachainXcmRouter<MsgQueue>>(
+            (Parent, Parachain(xcm_mock::PARA_A_ID)).into(),
+            Xcm(vec![
+                DescendOrigin(core_body),
+                Transact {
+                    origin_kind: OriginKind::Native,
+                    require_weight_at_most: weight,
+                    call: call.encode().into(),
+                },
+            ]),
+        ));
     });
+
+    ParaA::execute_with(|| {
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::MsgQueue(mock_msg_queue::Event::Fail(_, XcmError::BadOrigin))
+        )));
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::INV4(Event::DerivativeDispatched { .. })
+        )));
+    });
+
+    ParaA::execute_with(|| INV4::do_try_state().unwrap());
+    ParaB::execute_with(|| INV4::do_try_state().unwrap());
 }
 
 #[test]
-fn withdraw_vote_multisig_works() {
-    ExtBuilder::default().build().execute_with(|| {
+fn remote_voting_works() {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -1007,282 +4707,474 @@ fn withdraw_vote_multisig_works() {
             target: CHARLIE,
         }
         .into();
+        let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2);
 
         // Adding BOB.
-
         INV4::operate_multisig(
             RawOrigin::Signed(ALICE).into(),
             0u32,
             None,
             FeeAsset::Native,
-            Box::new(call1.clone()),
+            Box::new(call1),
+            vec![].try_into().unwrap(),
         )
         .unwrap();
 
         System::set_block_number(2);
 
-        // Adding CHARLIE
-
+        // Adding CHARLIE, waiting on BOB's vote.
         INV4::operate_multisig(
             RawOrigin::Signed(ALICE).into(),
             0u32,
             None,
             FeeAsset::Native,
             Box::new(call2.clone()),
+            vec![].try_into().unwrap(),
         )
         .unwrap();
 
-        // BOB votes nay.
+        let bob_location = MultiLocation {
+            parents: 1,
+            interior: X2(
+                Parachain(2),
+                Junction::AccountId32 {
+                    network: None,
+                    id: [1u8; 32],
+                },
+            ),
+        };
+        let remote_origin =
+            |location: MultiLocation| -> RuntimeOrigin { mock_msg_queue::Origin(location).into() };
 
-        assert_ok!(INV4::vote_multisig(
-            RawOrigin::Signed(BOB).into(),
-            0u32,
-            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
-            false
+        // Only the core can map locations to its members.
+        assert_err!(
+            INV4::set_remote_member(
+                RawOrigin::Signed(BOB).into(),
+                Box::new(bob_location.into()),
+                Some(BOB),
+            ),
+            BadOrigin
+        );
+
+        // Unmapped locations can't vote.
+        assert_err!(
+            INV4::vote_multisig_remote(
+                remote_origin(bob_location),
+                0u32,
+                call_hash,
+                false,
+                Weight::MAX
+            ),
+            Error::<Test>::RemoteMemberNotFound
+        );
+
+        assert_ok!(INV4::set_remote_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Box::new(bob_location.into()),
+            Some(BOB),
         ));
 
-        System::assert_has_event(
-            Event::MultisigVoteAdded {
+        System::assert_last_event(
+            Event::RemoteMemberSet {
                 core_id: 0u32,
-                executor_account: INV4::derive_core_account(0u32),
-                voter: BOB,
-                votes_added: Vote::Nay(CoreSeedBalance::get()),
-                current_votes: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    CoreSeedBalance::get(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([
-                        (ALICE, Vote::Aye(CoreSeedBalance::get())),
-                        (BOB, Vote::Nay(CoreSeedBalance::get())),
-                    ]))
-                    .unwrap(),
-                ),
-                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+                location: bob_location,
+                member: Some(BOB),
             }
             .into(),
         );
 
-        assert_eq!(
-            INV4::multisig(
+        assert_eq!(INV4::remote_members(0u32, bob_location), Some(BOB));
+
+        // Remote votes need a location origin.
+        assert_err!(
+            INV4::vote_multisig_remote(
+                RawOrigin::Signed(BOB).into(),
                 0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+                call_hash,
+                false,
+                Weight::MAX
             ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call2.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    CoreSeedBalance::get(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([
-                        (ALICE, Vote::Aye(CoreSeedBalance::get())),
-                        (BOB, Vote::Nay(CoreSeedBalance::get()))
-                    ]))
-                    .unwrap()
-                ),
-            })
+            BadOrigin
         );
 
-        // BOB withdraws his vote.
-
-        assert_ok!(INV4::withdraw_vote_multisig(
-            RawOrigin::Signed(BOB).into(),
+        // BOB votes nay from the other chain, counted like a local vote.
+        assert_ok!(INV4::vote_multisig_remote(
+            remote_origin(bob_location),
             0u32,
-            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            call_hash,
+            false,
+            Weight::MAX
         ));
 
         System::assert_has_event(
-            Event::MultisigVoteWithdrawn {
+            Event::MultisigVoteAdded {
                 core_id: 0u32,
                 executor_account: INV4::derive_core_account(0u32),
                 voter: BOB,
-                votes_removed: Vote::Nay(CoreSeedBalance::get()),
-                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+                votes_added: Vote::Nay(CoreSeedBalance::get()),
+                current_votes: Tally::from_parts(CoreSeedBalance::get(), CoreSeedBalance::get(), 2),
+                call_hash,
             }
             .into(),
         );
 
-        assert_eq!(
-            INV4::multisig(
+        // Removing the mapping stops remote votes.
+        assert_ok!(INV4::set_remote_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Box::new(bob_location.into()),
+            None,
+        ));
+
+        assert_eq!(INV4::remote_members(0u32, bob_location), None);
+
+        assert_err!(
+            INV4::vote_multisig_remote(
+                remote_origin(bob_location),
                 0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+                call_hash,
+                true,
+                Weight::MAX
             ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call2.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(CoreSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
-            })
+            Error::<Test>::RemoteMemberNotFound
         );
 
-        // ALICE also withdraws her vote.
+        assert_ok!(INV4::set_remote_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Box::new(bob_location.into()),
+            Some(BOB),
+        ));
 
-        assert_ok!(INV4::withdraw_vote_multisig(
-            RawOrigin::Signed(ALICE).into(),
+        // BOB changes vote to aye from the other chain, executing the call.
+        assert_ok!(INV4::vote_multisig_remote(
+            remote_origin(bob_location),
             0u32,
-            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            call_hash,
+            true,
+            Weight::MAX
         ));
 
         System::assert_has_event(
-            Event::MultisigVoteWithdrawn {
+            Event::MultisigExecuted {
                 core_id: 0u32,
                 executor_account: INV4::derive_core_account(0u32),
-                voter: ALICE,
-                votes_removed: Vote::Aye(CoreSeedBalance::get()),
-                call_hash: <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+                voter: BOB,
+                calls: vec![call2.clone()],
+                call_hash,
+                failed_call: None,
+                result: Ok(()),
             }
             .into(),
         );
 
-        assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
-            ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call2.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(Zero::zero(), Zero::zero(), BoundedBTreeMap::new()),
-            })
-        );
+        assert_eq!(INV4::multisig(0u32, call_hash), None);
     });
 }
 
 #[test]
-fn withdraw_vote_multisig_fails() {
-    ExtBuilder::default().build().execute_with(|| {
+fn xcm_remote_vote_works() {
+    MockNet::reset();
+
+    let bob_body = X1(Junction::AccountId32 {
+        network: None,
+        id: [1u8; 32],
+    });
+    let bob_location = MultiLocation {
+        parents: 1,
+        interior: X2(
+            Parachain(xcm_mock::PARA_B_ID),
+            Junction::AccountId32 {
+                network: None,
+                id: [1u8; 32],
+            },
+        ),
+    };
+
+    let call1: RuntimeCall = pallet::Call::token_mint {
+        amount: CoreSeedBalance::get(),
+        target: BOB,
+    }
+    .into();
+
+    let call2: RuntimeCall = pallet::Call::token_mint {
+        amount: CoreSeedBalance::get(),
+        target: CHARLIE,
+    }
+    .into();
+    let call_hash = <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2);
+
+    ParaA::execute_with(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
         )
         .unwrap();
 
-        System::set_block_number(1);
-
-        let call1: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: BOB,
-        }
-        .into();
-
-        let call2: RuntimeCall = pallet::Call::token_mint {
-            amount: CoreSeedBalance::get(),
-            target: CHARLIE,
-        }
-        .into();
-
-        // Adding BOB.
-
         INV4::operate_multisig(
             RawOrigin::Signed(ALICE).into(),
             0u32,
             None,
             FeeAsset::Native,
-            Box::new(call1.clone()),
+            Box::new(call1),
+            vec![].try_into().unwrap(),
         )
         .unwrap();
 
-        System::set_block_number(2);
-
-        // Adding CHARLIE
-
         INV4::operate_multisig(
             RawOrigin::Signed(ALICE).into(),
             0u32,
             None,
             FeeAsset::Native,
             Box::new(call2.clone()),
+            vec![].try_into().unwrap(),
         )
         .unwrap();
 
-        // BOB votes nay.
+        INV4::set_remote_member(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            Box::new(bob_location.into()),
+            Some(BOB),
+        )
+        .unwrap();
+    });
 
-        assert_ok!(INV4::vote_multisig(
-            RawOrigin::Signed(BOB).into(),
-            0u32,
-            <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
-            false
+    let vote: RuntimeCall = pallet::Call::vote_multisig_remote {
+        core_id: 0u32,
+        call_hash,
+        aye: true,
+        max_call_weight: call2.get_dispatch_info().weight,
+    }
+    .into();
+
+    // BOB votes from their account in the other chain.
+    ParaB::execute_with(|| {
+        assert_ok!(send_xcm::<ParachainXcmRouter<MsgQueue>>(
+            (Parent, Parachain(xcm_mock::PARA_A_ID)).into(),
+            Xcm(vec![
+                DescendOrigin(bob_body),
+                Transact {
+                    origin_kind: OriginKind::Xcm,
+                    require_weight_at_most: vote.get_dispatch_info().weight,
+                    call: vote.encode().into(),
+                },
+            ]),
         ));
+    });
 
-        // Multisig call not found.
+    ParaA::execute_with(|| {
+        System::assert_has_event(
+            Event::MultisigExecuted {
+                core_id: 0u32,
+                executor_account: INV4::derive_core_account(0u32),
+                voter: BOB,
+                calls: vec![call2.clone()],
+                call_hash,
+                failed_call: None,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            CoreAssets::accounts(CHARLIE, 0u32).free,
+            CoreSeedBalance::get()
+        );
+    });
+
+    ParaA::execute_with(|| INV4::do_try_state().unwrap());
+    ParaB::execute_with(|| INV4::do_try_state().unwrap());
+}
+
+#[test]
+fn core_handles_work() {
+    ExtBuilder::default().build_and_execute(|| {
+        for _ in 0..2 {
+            INV4::create_core(
+                RawOrigin::Signed(ALICE).into(),
+                Default::default(),
+                Perbill::from_percent(100),
+                Perbill::from_percent(100),
+                FeeAsset::Native,
+            )
+            .unwrap();
+        }
+
+        System::set_block_number(1);
+
+        let core_0_account = INV4::derive_core_account(0u32);
+        let core_1_account = INV4::derive_core_account(1u32);
+        let deposit = <Test as Config>::HandleDeposit::get();
+        let handle: handles::CoreHandleOf<Test> = b"invarch-dao".to_vec().try_into().unwrap();
+
+        Balances::transfer_keep_alive(
+            RawOrigin::Signed(ALICE).into(),
+            core_1_account.clone(),
+            deposit * 2,
+        )
+        .unwrap();
+
+        // Only cores can register handles.
         assert_err!(
-            INV4::withdraw_vote_multisig(
-                RawOrigin::Signed(BOB).into(),
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call1),
+            INV4::register_handle(RawOrigin::Signed(ALICE).into(), handle.clone()),
+            BadOrigin
+        );
+
+        assert_err!(
+            INV4::register_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                b"InvArch DAO".to_vec().try_into().unwrap(),
             ),
-            Error::<Test>::MultisigCallNotFound
+            Error::<Test>::InvalidHandle
         );
 
-        // Not a voter in this proposal.
         assert_err!(
-            INV4::withdraw_vote_multisig(
-                RawOrigin::Signed(CHARLIE).into(),
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2),
+            INV4::register_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                vec![].try_into().unwrap(),
             ),
-            Error::<Test>::NotAVoter
+            Error::<Test>::InvalidHandle
+        );
+
+        assert_ok!(INV4::register_handle(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            handle.clone(),
+        ));
+
+        System::assert_last_event(
+            Event::HandleRegistered {
+                core_id: 0u32,
+                handle: handle.clone(),
+            }
+            .into(),
         );
 
+        assert_eq!(Balances::reserved_balance(&core_0_account), deposit);
+        assert_eq!(INV4::core_by_handle(b"invarch-dao"), Some(0u32));
+
+        // The handle resolves to the core account and is preferred when unlooking up.
         assert_eq!(
-            INV4::multisig(
-                0u32,
-                <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&call2)
+            INV4Lookup::<Test>::lookup(MultiAddress::Raw(b"invarch-dao".to_vec())),
+            Ok(core_0_account.clone())
+        );
+        assert_eq!(
+            INV4Lookup::<Test>::lookup(MultiAddress::Index(0u32)),
+            Ok(core_0_account.clone())
+        );
+        assert_eq!(
+            INV4Lookup::<Test>::lookup(MultiAddress::Raw(b"unknown-dao".to_vec())),
+            Err(LookupError)
+        );
+        assert_eq!(
+            INV4Lookup::<Test>::unlookup(core_0_account.clone()),
+            MultiAddress::Raw(b"invarch-dao".to_vec())
+        );
+        assert_eq!(
+            INV4Lookup::<Test>::unlookup(core_1_account.clone()),
+            MultiAddress::Index(1u32)
+        );
+
+        // Handles are unique and cores hold a single handle.
+        assert_err!(
+            INV4::register_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                handle.clone(),
             ),
-            Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(call2.clone().encode()).unwrap(),
-                fee_asset: FeeAsset::Native,
-                original_caller: ALICE,
-                metadata: None,
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    CoreSeedBalance::get(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([
-                        (ALICE, Vote::Aye(CoreSeedBalance::get())),
-                        (BOB, Vote::Nay(CoreSeedBalance::get()))
-                    ]))
-                    .unwrap()
-                ),
-            })
+            Error::<Test>::HandleAlreadyTaken
+        );
+
+        assert_err!(
+            INV4::register_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+                b"invarch".to_vec().try_into().unwrap(),
+            ),
+            Error::<Test>::CoreAlreadyHasHandle
+        );
+
+        // The handle moves to core 1 along with its deposit.
+        assert_err!(
+            INV4::transfer_handle(
+                Origin::Multisig(MultisigInternalOrigin::new(1u32)).into(),
+                0u32
+            ),
+            Error::<Test>::CoreAlreadyHasHandle
+        );
+
+        assert_ok!(INV4::transfer_handle(
+            Origin::Multisig(MultisigInternalOrigin::new(0u32)).into(),
+            1u32
+        ));
+
+        System::assert_last_event(
+            Event::HandleTransferred {
+                handle: handle.clone(),
+                from: 0u32,
+                to: 1u32,
+            }
+            .into(),
+        );
+
+        assert_eq!(Balances::reserved_balance(&core_0_account), 0u128);
+        assert_eq!(Balances::reserved_balance(&core_1_account), deposit);
+        assert_eq!(
+            INV4Lookup::<Test>::lookup(MultiAddress::Raw(b"invarch-dao".to_vec())),
+            Ok(core_1_account.clone())
+        );
+        assert_eq!(
+            INV4Lookup::<Test>::unlookup(core_0_account.clone()),
+            MultiAddress::Index(0u32)
+        );
+
+        assert_err!(
+            INV4::release_handle(Origin::Multisig(MultisigInternalOrigin::new(0u32)).into()),
+            Error::<Test>::HandleNotFound
+        );
+
+        assert_ok!(INV4::release_handle(
+            Origin::Multisig(MultisigInternalOrigin::new(1u32)).into()
+        ));
+
+        System::assert_last_event(
+            Event::HandleReleased {
+                core_id: 1u32,
+                handle,
+            }
+            .into(),
+        );
+
+        assert_eq!(Balances::reserved_balance(&core_1_account), 0u128);
+        assert_eq!(INV4::core_by_handle(b"invarch-dao"), None);
+        assert_eq!(
+            INV4Lookup::<Test>::lookup(MultiAddress::Raw(b"invarch-dao".to_vec())),
+            Err(LookupError)
         );
     });
 }
 
 #[test]
 fn core_address_matches() {
-    const ACCOUNT_IN_ASSET_HUB: [u8; 32] = [
-        147, 83, 7, 98, 71, 245, 98, 15, 146, 176, 22, 221, 20, 216, 188, 203, 166, 234, 117, 86,
-        56, 214, 204, 37, 238, 26, 161, 82, 2, 174, 180, 74,
-    ];
+    ExtBuilder::default().build_and_execute(|| {
+        const ACCOUNT_IN_ASSET_HUB: [u8; 32] = [
+            147, 83, 7, 98, 71, 245, 98, 15, 146, 176, 22, 221, 20, 216, 188, 203, 166, 234, 117,
+            86, 56, 214, 204, 37, 238, 26, 161, 82, 2, 174, 180, 74,
+        ];
 
-    let core_account = <Pallet<Test> as CoreAccountDerivation<Test>>::derive_core_account(0);
+        let core_account = <Pallet<Test> as CoreAccountDerivation<Test>>::derive_core_account(0);
 
-    let core_account_bytes: [u8; 32] = core_account.into();
+        let core_account_bytes: [u8; 32] = core_account.into();
 
-    assert_eq!(core_account_bytes, ACCOUNT_IN_ASSET_HUB);
+        assert_eq!(core_account_bytes, ACCOUNT_IN_ASSET_HUB);
+    });
 }
 
 // SRLabs tests.
 #[test]
 fn vote_multisig_stack_overflow() {
-    ExtBuilder::default().build().execute_with(|| {
+    ExtBuilder::default().build_and_execute(|| {
         INV4::create_core(
             RawOrigin::Signed(ALICE).into(),
-            vec![].try_into().unwrap(),
+            Default::default(),
             Perbill::from_percent(100),
             Perbill::from_percent(100),
             FeeAsset::Native,
@@ -1302,6 +5194,7 @@ fn vote_multisig_stack_overflow() {
             metadata: None,
             fee_asset: FeeAsset::Native,
             call: Box::new(call1.clone()),
+            depends_on: Default::default(),
         }
         .into();
 
@@ -1311,6 +5204,7 @@ fn vote_multisig_stack_overflow() {
                 metadata: None,
                 fee_asset: FeeAsset::Native,
                 call: Box::new(nested_call.clone()),
+                depends_on: Default::default(),
             }
             .into();
         }
@@ -1321,6 +5215,7 @@ fn vote_multisig_stack_overflow() {
             None,
             FeeAsset::Native,
             Box::new(call1.clone()),
+            vec![].try_into().unwrap(),
         )
         .unwrap();
 
@@ -1332,6 +5227,7 @@ fn vote_multisig_stack_overflow() {
             None,
             FeeAsset::Native,
             Box::new(nested_call.clone()),
+            vec![].try_into().unwrap(),
         )
         .unwrap();
 
@@ -1341,20 +5237,13 @@ fn vote_multisig_stack_overflow() {
                 <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&nested_call)
             ),
             Some(MultisigOperation {
-                actual_call: BoundedCallBytes::<Test>::try_from(nested_call.clone().encode())
+                actual_call: BoundedCallBytes::<Test>::try_from(vec![nested_call.clone()].encode())
                     .unwrap(),
                 fee_asset: FeeAsset::Native,
+                depends_on: Default::default(),
                 original_caller: ALICE,
                 metadata: None,
-                tally: Tally::from_parts(
-                    CoreSeedBalance::get(),
-                    Zero::zero(),
-                    BoundedBTreeMap::try_from(BTreeMap::from([(
-                        ALICE,
-                        Vote::Aye(CoreSeedBalance::get())
-                    )]))
-                    .unwrap()
-                ),
+                tally: Tally::from_parts(CoreSeedBalance::get(), Zero::zero(), 1),
             })
         );
 
@@ -1363,7 +5252,8 @@ fn vote_multisig_stack_overflow() {
                 RawOrigin::Signed(BOB).into(),
                 0u32,
                 <<Test as frame_system::Config>::Hashing as Hash>::hash_of(&nested_call),
-                true
+                true,
+                Weight::MAX
             ),
             Error::<Test>::FailedDecodingCall
         );
@@ -0,0 +1,367 @@
+//! Local XCM network for testing cross-chain core origins.
+//!
+//! Two parachains running the mock runtime are connected through a minimal relay chain.
+//! `ParaA` uses the mock's `ParaId`, so its cores are the ones the pallet converts into core origins.
+//! Locations in other chains are passed through as an origin of the mock queue, standing in for `pallet_xcm`.
+
+use super::mock::{ExtBuilder, MsgQueue, RuntimeCall, RuntimeOrigin, System, Test};
+use crate::xcm_conversion::{CoreLocationToAccountId, CoreOriginConverter};
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, EnsureOrigin, Everything, Nothing},
+    weights::Weight,
+};
+use xcm::v3::{InteriorMultiLocation, Junctions, MultiLocation, OriginKind};
+use xcm_builder::{AllowUnpaidExecutionFrom, FixedWeightBounds, SovereignSignedViaLocation};
+use xcm_executor::{traits::ConvertOrigin, XcmExecutor};
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+
+pub const PARA_A_ID: u32 = 2125;
+pub const PARA_B_ID: u32 = 2;
+
+/// Minimal stand-in for the XCMP queue, executing incoming messages right away.
+#[frame_support::pallet]
+pub mod mock_msg_queue {
+    use codec::{Decode, Encode};
+    use frame_support::pallet_prelude::*;
+    use sp_runtime::traits::Hash;
+    use sp_std::vec::Vec;
+    use xcm::{
+        v3::{prelude::*, Xcm},
+        VersionedXcm,
+    };
+    use xcm_simulator::{
+        DmpMessageHandlerT, ParaId, RelayBlockNumber, XcmpMessageFormat, XcmpMessageHandlerT,
+    };
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type XcmExecutor: ExecuteXcm<Self::RuntimeCall>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Location an `OriginKind::Xcm` message came from.
+    #[pallet::origin]
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Origin(pub MultiLocation);
+
+    #[pallet::storage]
+    pub(super) type ParachainId<T: Config> = StorageValue<_, ParaId, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Success(T::Hash),
+        Fail(T::Hash, XcmError),
+        BadVersion(T::Hash),
+    }
+
+    impl<T: Config> Get<ParaId> for Pallet<T> {
+        fn get() -> ParaId {
+            ParachainId::<T>::get()
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub fn set_para_id(para_id: ParaId) {
+            ParachainId::<T>::put(para_id);
+        }
+
+        fn handle_xcmp_message(
+            sender: ParaId,
+            xcm: VersionedXcm<T::RuntimeCall>,
+            max_weight: Weight,
+        ) {
+            let hash = Encode::using_encoded(&xcm, T::Hashing::hash);
+            let mut message_hash = Encode::using_encoded(&xcm, sp_io::hashing::blake2_256);
+
+            let event = match Xcm::<T::RuntimeCall>::try_from(xcm) {
+                Ok(xcm) => {
+                    let location = (Parent, Parachain(sender.into()));
+
+                    match T::XcmExecutor::prepare_and_execute(
+                        location,
+                        xcm,
+                        &mut message_hash,
+                        max_weight,
+                        Weight::zero(),
+                    ) {
+                        Outcome::Complete(_) => Event::Success(hash),
+                        Outcome::Incomplete(_, e) | Outcome::Error(e) => Event::Fail(hash, e),
+                    }
+                }
+                Err(()) => Event::BadVersion(hash),
+            };
+
+            Self::deposit_event(event);
+        }
+    }
+
+    impl<T: Config> XcmpMessageHandlerT for Pallet<T> {
+        fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+            iter: I,
+            max_weight: Weight,
+        ) -> Weight {
+            for (sender, _sent_at, data) in iter {
+                let mut data_ref = data;
+                let _ = XcmpMessageFormat::decode(&mut data_ref)
+                    .expect("Simulator encodes with versioned xcm format; qed");
+
+                let mut remaining_fragments = data_ref;
+                while !remaining_fragments.is_empty() {
+                    match VersionedXcm::<T::RuntimeCall>::decode(&mut remaining_fragments) {
+                        Ok(xcm) => Self::handle_xcmp_message(sender, xcm, max_weight),
+                        Err(_) => break,
+                    }
+                }
+            }
+            max_weight
+        }
+    }
+
+    impl<T: Config> DmpMessageHandlerT for Pallet<T> {
+        fn handle_dmp_messages(
+            _iter: impl Iterator<Item = (RelayBlockNumber, Vec<u8>)>,
+            limit: Weight,
+        ) -> Weight {
+            limit
+        }
+    }
+}
+
+/// Converts `OriginKind::Xcm` origins into the location they came from, like `pallet_xcm::XcmPassthrough`.
+pub struct LocationPassthrough;
+impl ConvertOrigin<RuntimeOrigin> for LocationPassthrough {
+    fn convert_origin(
+        origin: impl Into<MultiLocation>,
+        kind: OriginKind,
+    ) -> Result<RuntimeOrigin, MultiLocation> {
+        let origin = origin.into();
+
+        match kind {
+            OriginKind::Xcm => Ok(mock_msg_queue::Origin(origin).into()),
+            _ => Err(origin),
+        }
+    }
+}
+
+/// Ensures the origin is a location passed through from XCM, like `pallet_xcm::EnsureXcm`.
+pub struct EnsureLocation;
+impl EnsureOrigin<RuntimeOrigin> for EnsureLocation {
+    type Success = MultiLocation;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let origin: Result<mock_msg_queue::Origin, RuntimeOrigin> = o.into();
+        origin.map(|mock_msg_queue::Origin(location)| location)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(mock_msg_queue::Origin(MultiLocation::parent()).into())
+    }
+}
+
+parameter_types! {
+    pub const UnitWeightCost: Weight = Weight::from_parts(10, 10);
+    pub const MaxInstructions: u32 = 100;
+    pub UniversalLocation: InteriorMultiLocation = Junctions::Here;
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+    type RuntimeCall = RuntimeCall;
+    type XcmSender = ();
+    type AssetTransactor = ();
+    type OriginConverter = (
+        CoreOriginConverter<Test, RuntimeOrigin>,
+        SovereignSignedViaLocation<CoreLocationToAccountId<Test>, RuntimeOrigin>,
+        LocationPassthrough,
+    );
+    type IsReserve = Nothing;
+    type IsTeleporter = Nothing;
+    type UniversalLocation = UniversalLocation;
+    type Barrier = AllowUnpaidExecutionFrom<Everything>;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type Trader = ();
+    type ResponseHandler = ();
+    type AssetTrap = ();
+    type AssetLocker = ();
+    type AssetExchanger = ();
+    type AssetClaims = ();
+    type SubscriptionService = ();
+    type PalletInstancesInfo = ();
+    type MaxAssetsIntoHolding = ConstU32<64>;
+    type FeeManager = ();
+    type MessageExporter = ();
+    type UniversalAliases = Nothing;
+    type CallDispatcher = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type Aliasers = Nothing;
+}
+
+impl mock_msg_queue::Config for Test {
+    type RuntimeEvent = super::mock::RuntimeEvent;
+    type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+pub mod relay {
+    use super::{MaxInstructions, UnitWeightCost, UniversalLocation};
+    use frame_support::{
+        derive_impl, parameter_types,
+        traits::{ConstU32, Everything, Nothing, ProcessMessage, ProcessMessageError},
+        weights::{Weight, WeightMeter},
+    };
+    use sp_runtime::BuildStorage;
+    use xcm::v3::Junction;
+    use xcm_builder::{AllowUnpaidExecutionFrom, FixedWeightBounds};
+    use xcm_simulator::{AggregateMessageOrigin, ProcessXcmMessage, UmpQueueId};
+
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+
+    frame_support::construct_runtime!(
+        pub enum Runtime
+        {
+            System: frame_system,
+            MessageQueue: pallet_message_queue,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+    impl frame_system::Config for Runtime {
+        type RuntimeOrigin = RuntimeOrigin;
+        type RuntimeCall = RuntimeCall;
+        type RuntimeEvent = RuntimeEvent;
+        type Block = Block;
+        type PalletInfo = PalletInfo;
+        type BaseCallFilter = Everything;
+        type OnSetCode = ();
+    }
+
+    pub struct XcmConfig;
+    impl xcm_executor::Config for XcmConfig {
+        type RuntimeCall = RuntimeCall;
+        type XcmSender = ();
+        type AssetTransactor = ();
+        type OriginConverter = ();
+        type IsReserve = Nothing;
+        type IsTeleporter = Nothing;
+        type UniversalLocation = UniversalLocation;
+        type Barrier = AllowUnpaidExecutionFrom<Everything>;
+        type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+        type Trader = ();
+        type ResponseHandler = ();
+        type AssetTrap = ();
+        type AssetLocker = ();
+        type AssetExchanger = ();
+        type AssetClaims = ();
+        type SubscriptionService = ();
+        type PalletInstancesInfo = ();
+        type MaxAssetsIntoHolding = ConstU32<64>;
+        type FeeManager = ();
+        type MessageExporter = ();
+        type UniversalAliases = Nothing;
+        type CallDispatcher = RuntimeCall;
+        type SafeCallFilter = Everything;
+        type Aliasers = Nothing;
+    }
+
+    parameter_types! {
+        pub MessageQueueServiceWeight: Weight = Weight::from_parts(1_000_000_000, 1_000_000);
+    }
+
+    pub struct MessageProcessor;
+    impl ProcessMessage for MessageProcessor {
+        type Origin = AggregateMessageOrigin;
+
+        fn process_message(
+            message: &[u8],
+            origin: Self::Origin,
+            meter: &mut WeightMeter,
+            id: &mut [u8; 32],
+        ) -> Result<bool, ProcessMessageError> {
+            let para = match origin {
+                AggregateMessageOrigin::Ump(UmpQueueId::Para(para)) => para,
+            };
+            ProcessXcmMessage::<Junction, xcm_executor::XcmExecutor<XcmConfig>, RuntimeCall>::process_message(
+                message,
+                Junction::Parachain(para.into()),
+                meter,
+                id,
+            )
+        }
+    }
+
+    impl pallet_message_queue::Config for Runtime {
+        type RuntimeEvent = RuntimeEvent;
+        type Size = u32;
+        type HeapSize = ConstU32<65_536>;
+        type MaxStale = ConstU32<16>;
+        type ServiceWeight = MessageQueueServiceWeight;
+        type MessageProcessor = MessageProcessor;
+        type QueueChangeHandler = ();
+        type QueuePausedQuery = ();
+        type WeightInfo = ();
+    }
+
+    pub fn relay_ext() -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
+
+pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MsgQueue::set_para_id(para_id.into());
+    });
+    ext
+}
+
+decl_test_parachain! {
+    pub struct ParaA {
+        Runtime = Test,
+        XcmpMessageHandler = MsgQueue,
+        DmpMessageHandler = MsgQueue,
+        new_ext = para_ext(PARA_A_ID),
+    }
+}
+
+decl_test_parachain! {
+    pub struct ParaB {
+        Runtime = Test,
+        XcmpMessageHandler = MsgQueue,
+        DmpMessageHandler = MsgQueue,
+        new_ext = para_ext(PARA_B_ID),
+    }
+}
+
+decl_test_relay_chain! {
+    pub struct Relay {
+        Runtime = relay::Runtime,
+        RuntimeCall = relay::RuntimeCall,
+        RuntimeEvent = relay::RuntimeEvent,
+        XcmConfig = relay::XcmConfig,
+        MessageQueue = relay::MessageQueue,
+        System = relay::System,
+        new_ext = relay::relay_ext(),
+    }
+}
+
+decl_test_network! {
+    pub struct MockNet {
+        relay_chain = Relay,
+        parachains = vec![
+            (2125, ParaA),
+            (2, ParaB),
+        ],
+    }
+}
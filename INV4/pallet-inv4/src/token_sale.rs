@@ -0,0 +1,215 @@
+//! Voting Token Sales.
+//!
+//! ## Overview
+//!
+//! This module allows cores to open a bounded sale of their voting token, letting anyone join the core
+//! without a vote for every newcomer.
+//!
+//! A sale is defined by the price per voting token in the asset accepted as payment, the maximum amount of
+//! voting tokens to be sold and the blocks in which the sale starts and ends.
+//! Prices are kept in the payment asset's own balance type, so costs are never silently truncated.
+//! Proceeds go straight to the core account and the bought tokens are minted to the buyer.
+//!
+//! ### Core functionalities:
+//! - `inner_open_token_sale`: Opens a new sale for the core's voting token.
+//! - `inner_close_token_sale`: Closes the core's current sale.
+//! - `inner_buy_tokens`: Buys voting tokens from an ongoing sale.
+
+use super::pallet::*;
+use crate::{
+    account_derivation::CoreAccountDerivation,
+    fee_handling::{FeeAssetBalance, FeeAssetBalanceOf},
+    origin::{ensure_multisig, INV4Origin},
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungibles::{Balanced, Inspect, Mutate},
+        tokens::{Fortitude, Precision, Preservation},
+        Currency, ExistenceRequirement,
+    },
+};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use scale_info::TypeInfo;
+use sp_arithmetic::traits::{CheckedAdd, CheckedMul};
+use sp_runtime::{traits::Zero, ArithmeticError};
+
+/// Details of a core's voting token sale.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct TokenSale<Price, Balance, BlockNumber> {
+    /// Price of a single voting token in the asset accepted as payment.
+    pub price: Price,
+    /// Maximum amount of voting tokens to be sold.
+    pub cap: Balance,
+    /// Amount of voting tokens sold so far.
+    pub sold: Balance,
+    /// First block in which tokens can be bought.
+    pub start: BlockNumber,
+    /// Last block in which tokens can be bought.
+    pub end: BlockNumber,
+}
+
+pub type TokenSaleOf<T> = TokenSale<FeeAssetBalanceOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
+type RelayBalanceOf<T> =
+    <<T as Config>::Tokens as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+    <T as frame_system::Config>::AccountId: From<[u8; 32]>,
+{
+    /// Inner function for the open_token_sale call.
+    pub(crate) fn inner_open_token_sale(
+        origin: OriginFor<T>,
+        price: FeeAssetBalanceOf<T>,
+        cap: BalanceOf<T>,
+        start: BlockNumberFor<T>,
+        end: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        // Grab the core id from the origin
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        let now = frame_system::Pallet::<T>::block_number();
+
+        ensure!(
+            start <= end && now <= end,
+            Error::<T>::InvalidTokenSalePeriod
+        );
+
+        TokenSales::<T>::try_mutate(core_id, |maybe_sale| -> DispatchResult {
+            // Only allow replacing an existing sale once it has ended
+            if let Some(sale) = maybe_sale {
+                ensure!(sale.end < now, Error::<T>::TokenSaleAlreadyExists);
+            }
+
+            *maybe_sale = Some(TokenSale {
+                price: price.clone(),
+                cap,
+                sold: Zero::zero(),
+                start,
+                end,
+            });
+
+            Self::deposit_event(Event::TokenSaleOpened {
+                core_id,
+                price,
+                cap,
+                start,
+                end,
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Inner function for the close_token_sale call.
+    pub(crate) fn inner_close_token_sale(origin: OriginFor<T>) -> DispatchResult {
+        // Grab the core id from the origin
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        let sale = TokenSales::<T>::take(core_id).ok_or(Error::<T>::TokenSaleNotFound)?;
+
+        Self::deposit_event(Event::TokenSaleClosed {
+            core_id,
+            sold: sale.sold,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the buy_tokens call.
+    pub(crate) fn inner_buy_tokens(
+        origin: OriginFor<T>,
+        core_id: T::CoreId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let buyer = ensure_signed(origin)?;
+
+        TokenSales::<T>::try_mutate(core_id, |maybe_sale| -> DispatchResult {
+            let sale = maybe_sale.as_mut().ok_or(Error::<T>::TokenSaleNotFound)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+
+            ensure!(
+                sale.start <= now && now <= sale.end,
+                Error::<T>::TokenSaleNotActive
+            );
+
+            // Make sure the sale's cap is respected
+            let sold = sale
+                .sold
+                .checked_add(&amount)
+                .ok_or(ArithmeticError::Overflow)?;
+
+            ensure!(sold <= sale.cap, Error::<T>::TokenSaleCapExceeded);
+
+            // Make sure the core's max supply is respected
+            Self::ensure_within_max_supply(core_id, amount)?;
+
+            let core_account = Self::derive_core_account(core_id);
+
+            // Send the proceeds straight to the core account
+            let cost = match &sale.price {
+                FeeAssetBalance::Native(price) => {
+                    let cost = price
+                        .checked_mul(&amount)
+                        .ok_or(ArithmeticError::Overflow)?;
+
+                    <T as Config>::Currency::transfer(
+                        &buyer,
+                        &core_account,
+                        cost,
+                        ExistenceRequirement::KeepAlive,
+                    )?;
+
+                    FeeAssetBalance::Native(cost)
+                }
+
+                FeeAssetBalance::Relay(price) => {
+                    // The amount of voting tokens has to fit in the relay asset's balance type
+                    let relay_amount: RelayBalanceOf<T> = TryInto::<u128>::try_into(amount)
+                        .ok()
+                        .and_then(|amount| amount.try_into().ok())
+                        .ok_or(ArithmeticError::Overflow)?;
+
+                    let cost = price
+                        .checked_mul(&relay_amount)
+                        .ok_or(ArithmeticError::Overflow)?;
+
+                    let credit = <T as Config>::Tokens::withdraw(
+                        T::RelayAssetId::get(),
+                        &buyer,
+                        cost,
+                        Precision::Exact,
+                        Preservation::Protect,
+                        Fortitude::Polite,
+                    )?;
+
+                    <T as Config>::Tokens::resolve(&core_account, credit)
+                        .map_err(|_| Error::<T>::TokenSalePaymentFailed)?;
+
+                    FeeAssetBalance::Relay(cost)
+                }
+            };
+
+            // Mint the bought voting tokens to the buyer
+            T::AssetsProvider::mint_into(core_id, &buyer, amount)?;
+
+            sale.sold = sold;
+
+            Self::deposit_event(Event::TokensBought {
+                core_id,
+                buyer,
+                amount,
+                cost,
+            });
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,123 @@
+//! Storage Consistency Checks.
+//!
+//! ## Overview
+//!
+//! This module checks the invariants tying this pallet's storage items together. It's run by the `try_state` hook
+//! under the `try-runtime` feature and after every unit test.
+//!
+//! ### Core functionalities:
+//! - `do_try_state`: Checks all of the invariants below.
+//! - `try_state_cores`: Checks that [`CoreByAccount`] mirrors [`CoreStorage`].
+//! - `try_state_members`: Checks that [`CoreMembers`] only lists holders of a non-zero balance of the core's voting
+//!   token, and that [`MemberCores`] and [`CoreMemberCount`] agree with it. Whether every holder is listed can't be
+//!   checked here, as `fungibles` can't list the holders of an asset, so it's left to the runtime's or mock's checks
+//!   over their token storage.
+//! - `try_state_multisigs`: Checks that every [`Multisig`] belongs to an existing core and that its tally matches the
+//!   votes recorded in [`MultisigVotes`].
+
+use super::pallet::*;
+use crate::voting::Vote;
+use frame_support::{ensure, traits::fungibles::Inspect};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    TryRuntimeError,
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+impl<T: Config> Pallet<T> {
+    /// Checks the consistency of the pallet's storage.
+    pub fn do_try_state() -> Result<(), TryRuntimeError> {
+        Self::try_state_cores()?;
+        Self::try_state_members()?;
+        Self::try_state_multisigs()
+    }
+
+    fn try_state_cores() -> Result<(), TryRuntimeError> {
+        for (core_id, core) in CoreStorage::<T>::iter() {
+            ensure!(
+                CoreByAccount::<T>::get(&core.account) == Some(core_id),
+                "Core account is not mapped to its core"
+            );
+        }
+
+        for (account, core_id) in CoreByAccount::<T>::iter() {
+            ensure!(
+                CoreStorage::<T>::get(core_id).map(|core| core.account) == Some(account),
+                "Account is mapped to a core it doesn't belong to"
+            );
+        }
+
+        Ok(())
+    }
+
+    fn try_state_members() -> Result<(), TryRuntimeError> {
+        let mut member_counts: BTreeMap<T::CoreId, u32> = BTreeMap::new();
+
+        for (core_id, member, ()) in CoreMembers::<T>::iter() {
+            ensure!(
+                !T::AssetsProvider::total_balance(core_id, &member).is_zero(),
+                "Core member holds no voting tokens"
+            );
+
+            ensure!(
+                MemberCores::<T>::contains_key(&member, core_id),
+                "Core member is missing from MemberCores"
+            );
+
+            member_counts.entry(core_id).or_default().saturating_inc();
+        }
+
+        for (member, core_id, ()) in MemberCores::<T>::iter() {
+            ensure!(
+                CoreMembers::<T>::contains_key(core_id, &member),
+                "MemberCores lists an account that is not a core member"
+            );
+        }
+
+        for (core_id, count) in CoreMemberCount::<T>::iter() {
+            ensure!(
+                member_counts.remove(&core_id).unwrap_or_default() == count,
+                "CoreMemberCount doesn't match the amount of core members"
+            );
+        }
+
+        ensure!(
+            member_counts.is_empty(),
+            "Core with members has no CoreMemberCount"
+        );
+
+        Ok(())
+    }
+
+    fn try_state_multisigs() -> Result<(), TryRuntimeError> {
+        for (core_id, call_hash, proposal) in Multisig::<T>::iter() {
+            ensure!(
+                CoreStorage::<T>::contains_key(core_id),
+                "Multisig proposal belongs to a core that doesn't exist"
+            );
+
+            let (mut ayes, mut nays, mut voters) = (Zero::zero(), Zero::zero(), 0u32);
+
+            for vote in MultisigVotes::<T>::iter_prefix_values((core_id, call_hash)) {
+                match vote {
+                    Vote::Aye(v) => ayes.saturating_accrue(v),
+                    Vote::Nay(v) => nays.saturating_accrue(v),
+                }
+
+                voters.saturating_inc();
+            }
+
+            ensure!(
+                proposal.tally.ayes == ayes && proposal.tally.nays == nays,
+                "Multisig tally doesn't match its vote records"
+            );
+
+            ensure!(
+                proposal.tally.voters == voters,
+                "Multisig voter count doesn't match its vote records"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+//! Voting Token Vesting.
+//!
+//! ## Overview
+//!
+//! This module allows cores to mint their voting token under a vesting schedule, useful for contributor compensation.
+//! Tokens minted this way count towards the member's voting power right away, but the locked portion can't be
+//! transferred or burned until it's released.
+//!
+//! Schedules can either unlock linearly between two blocks or all at once after a cliff. Unlocked tokens are only
+//! released when the member calls `vest`, which updates the schedules in storage.
+//!
+//! ### Core functionalities:
+//! - `inner_token_mint_vested`: Mints the core's voting token to a target under a vesting schedule.
+//! - `inner_vest`: Releases the caller's unlocked tokens.
+//! - `ensure_unlocked`: Utility function for checking if an amount of a member's tokens isn't locked by vesting.
+
+use super::pallet::*;
+use crate::origin::{ensure_multisig, INV4Origin};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::*,
+    traits::fungibles::{Inspect, Mutate},
+};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Perbill,
+};
+
+/// How the tokens of a vesting schedule are unlocked over time.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub enum VestingSchedule<BlockNumber> {
+    /// Tokens are unlocked linearly from `start` until `end`.
+    Linear {
+        start: BlockNumber,
+        end: BlockNumber,
+    },
+    /// All tokens are unlocked at once in block `unlock_at`.
+    Cliff { unlock_at: BlockNumber },
+}
+
+/// Voting tokens minted to a member under a vesting schedule.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+pub struct Vesting<Balance, BlockNumber> {
+    /// Total amount of tokens minted under this schedule.
+    pub total: Balance,
+    /// Amount of tokens already released.
+    pub released: Balance,
+    /// Schedule defining how the tokens are unlocked.
+    pub schedule: VestingSchedule<BlockNumber>,
+}
+
+pub type VestingOf<T> = Vesting<BalanceOf<T>, BlockNumberFor<T>>;
+
+impl<Balance, BlockNumber> Vesting<Balance, BlockNumber>
+where
+    Balance: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+    BlockNumber: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+{
+    /// Amount of tokens unlocked by the schedule at block `now`, including the ones already released.
+    pub fn unlocked_at(&self, now: BlockNumber) -> Balance {
+        match self.schedule {
+            VestingSchedule::Linear { start, end } => {
+                if now >= end {
+                    self.total
+                } else if now <= start {
+                    Zero::zero()
+                } else {
+                    Perbill::from_rational(now - start, end - start).mul_floor(self.total)
+                }
+            }
+            VestingSchedule::Cliff { unlock_at } => {
+                if now >= unlock_at {
+                    self.total
+                } else {
+                    Zero::zero()
+                }
+            }
+        }
+    }
+
+    /// Amount of tokens that are still locked.
+    pub fn locked(&self) -> Balance {
+        self.total.saturating_sub(self.released)
+    }
+}
+
+impl<T: Config> Pallet<T>
+where
+    Result<INV4Origin<T>, <T as frame_system::Config>::RuntimeOrigin>:
+        From<<T as frame_system::Config>::RuntimeOrigin>,
+{
+    /// Inner function for the token_mint_vested call.
+    pub(crate) fn inner_token_mint_vested(
+        origin: OriginFor<T>,
+        amount: BalanceOf<T>,
+        target: T::AccountId,
+        schedule: VestingSchedule<BlockNumberFor<T>>,
+    ) -> DispatchResult {
+        // Grab the core id from the origin
+        let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
+        let core_id = core_origin.id;
+
+        ensure!(!amount.is_zero(), Error::<T>::InvalidVestingSchedule);
+
+        if let VestingSchedule::Linear { start, end } = schedule {
+            ensure!(start < end, Error::<T>::InvalidVestingSchedule);
+        }
+
+        // Make sure the core's max supply is respected.
+        Self::ensure_within_max_supply(core_id, amount)?;
+
+        let vesting = Vesting {
+            total: amount,
+            released: Zero::zero(),
+            schedule,
+        };
+
+        VestingSchedules::<T>::try_mutate(core_id, &target, |schedules| {
+            schedules
+                .try_push(vesting.clone())
+                .map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)
+        })?;
+
+        // Mint the core's voting token to the target.
+        T::AssetsProvider::mint_into(core_id, &target, amount)?;
+
+        Self::deposit_event(Event::VestingScheduleAdded {
+            core_id,
+            target: target.clone(),
+            vesting,
+        });
+
+        Self::deposit_event(Event::Minted {
+            core_id,
+            target,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Inner function for the vest call.
+    pub(crate) fn inner_vest(origin: OriginFor<T>, core_id: T::CoreId) -> DispatchResult {
+        let member = ensure_signed(origin)?;
+
+        let now = frame_system::Pallet::<T>::block_number();
+
+        let released = VestingSchedules::<T>::try_mutate_exists(
+            core_id,
+            &member,
+            |maybe_schedules| -> Result<BalanceOf<T>, DispatchError> {
+                let schedules = maybe_schedules
+                    .as_mut()
+                    .ok_or(Error::<T>::NoVestingSchedule)?;
+
+                let mut released: BalanceOf<T> = Zero::zero();
+
+                // Release everything unlocked so far
+                for vesting in schedules.iter_mut() {
+                    let unlocked = vesting.unlocked_at(now);
+
+                    released.saturating_accrue(unlocked.saturating_sub(vesting.released));
+                    vesting.released = unlocked;
+                }
+
+                // Drop the schedules that are done
+                schedules.retain(|vesting| !vesting.locked().is_zero());
+
+                if schedules.is_empty() {
+                    *maybe_schedules = None;
+                }
+
+                Ok(released)
+            },
+        )?;
+
+        Self::deposit_event(Event::Vested {
+            core_id,
+            target: member,
+            amount: released,
+        });
+
+        Ok(())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Amount of a member's voting tokens still locked by vesting schedules.
+    pub fn locked_balance(core_id: T::CoreId, who: &T::AccountId) -> BalanceOf<T> {
+        VestingSchedules::<T>::get(core_id, who)
+            .iter()
+            .fold(Zero::zero(), |locked: BalanceOf<T>, vesting| {
+                locked.saturating_add(vesting.locked())
+            })
+    }
+
+    /// Checks if `amount` of a member's voting tokens can be moved without touching the ones locked by vesting.
+    pub fn ensure_unlocked(
+        core_id: T::CoreId,
+        who: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let locked = Self::locked_balance(core_id, who);
+
+        if locked.is_zero() {
+            return Ok(());
+        }
+
+        ensure!(
+            T::AssetsProvider::balance(core_id, who).saturating_sub(amount) >= locked,
+            Error::<T>::InsufficientUnlockedBalance
+        );
+
+        Ok(())
+    }
+}
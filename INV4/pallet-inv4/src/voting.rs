@@ -6,19 +6,26 @@
 //! Members each have a balance in voting tokens and this balance differentiate their voting power
 //! as every vote utilizes the entire `power` of the said member.
 //! This empowers decision-making where certain members possess greater influence.
+//!
+//! Individual votes are kept in [`MultisigVotes`], so the [`Tally`] only holds aggregated counts
+//! and voting costs the same no matter how many members already voted.
 
-use crate::{origin::INV4Origin, BalanceOf, Config, CoreStorage, Error, Multisig, Pallet};
+use crate::{
+    origin::INV4Origin, BalanceOf, Config, CoreMemberCount, CoreStorage, Error, Multisig,
+    MultisigVotes, Pallet,
+};
 use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use core::marker::PhantomData;
 use frame_support::{
     pallet_prelude::{Member, RuntimeDebug},
     traits::{fungibles::Inspect, PollStatus, VoteTally},
-    BoundedBTreeMap, CloneNoBound, EqNoBound, Parameter, PartialEqNoBound, RuntimeDebugNoBound,
+    CloneNoBound, EqNoBound, Parameter, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
+use primitives::MinimumVoters;
 use scale_info::TypeInfo;
 use sp_runtime::{
-    traits::{One, Zero},
+    traits::{One, Saturating, Zero},
     DispatchError, Perbill,
 };
 use sp_std::vec::Vec;
@@ -42,56 +49,93 @@ pub type Core<T> = <T as Config>::CoreId;
 pub struct Tally<T: Config> {
     pub ayes: Votes<T>,
     pub nays: Votes<T>,
-    pub records: BoundedBTreeMap<T::AccountId, Vote<Votes<T>>, T::MaxCallers>,
+    /// Number of members that voted.
+    pub voters: u32,
     dummy: PhantomData<T>,
 }
 
 impl<T: Config> Tally<T> {
     /// Allows for building a `Tally` manually.
-    pub fn from_parts(
-        ayes: Votes<T>,
-        nays: Votes<T>,
-        records: BoundedBTreeMap<T::AccountId, Vote<Votes<T>>, T::MaxCallers>,
-    ) -> Self {
+    pub fn from_parts(ayes: Votes<T>, nays: Votes<T>, voters: u32) -> Self {
         Tally {
             ayes,
             nays,
-            records,
+            voters,
             dummy: PhantomData,
         }
     }
 
-    /// Check if a vote is valid and add the member's total voting token balance to the tally.
+    /// Check if a vote is valid and add the member's total voting token balance to the tally,
+    /// replacing their previous vote if any.
+    ///
+    /// Passing `None` as the vote removes the member's vote.
     pub fn process_vote(
         &mut self,
+        core_id: T::CoreId,
+        call_hash: T::Hash,
         account: T::AccountId,
         maybe_vote: Option<Vote<Votes<T>>>,
     ) -> Result<Vote<Votes<T>>, DispatchError> {
-        let votes = if let Some(vote) = maybe_vote {
-            self.records
-                .try_insert(account, vote)
-                .map_err(|_| Error::<T>::MaxCallersExceeded)?;
-            vote
-        } else {
-            self.records.remove(&account).ok_or(Error::<T>::NotAVoter)?
+        let previous_vote = MultisigVotes::<T>::get((core_id, call_hash, &account));
+
+        let votes = match (maybe_vote, previous_vote) {
+            (Some(vote), _) => {
+                MultisigVotes::<T>::insert((core_id, call_hash, &account), vote);
+                vote
+            }
+            (None, Some(vote)) => {
+                MultisigVotes::<T>::remove((core_id, call_hash, &account));
+                vote
+            }
+            (None, None) => return Err(Error::<T>::NotAVoter.into()),
         };
 
-        let (ayes, nays) = self.records.values().fold(
-            (Zero::zero(), Zero::zero()),
-            |(mut ayes, mut nays): (Votes<T>, Votes<T>), vote| {
-                match vote {
-                    Vote::Aye(v) => ayes += *v,
-                    Vote::Nay(v) => nays += *v,
-                };
-                (ayes, nays)
-            },
-        );
+        match previous_vote {
+            Some(vote) => self.remove_vote(vote),
+            None => self.voters.saturating_inc(),
+        }
 
-        self.ayes = ayes;
-        self.nays = nays;
+        match maybe_vote {
+            Some(vote) => self.add_vote(vote),
+            None => self.voters.saturating_dec(),
+        }
 
         Ok(votes)
     }
+
+    /// Returns the tally resulting from a member's vote, without touching storage.
+    pub fn with_vote(
+        &self,
+        core_id: T::CoreId,
+        call_hash: T::Hash,
+        account: &T::AccountId,
+        vote: Vote<Votes<T>>,
+    ) -> Self {
+        let mut tally = self.clone();
+
+        match MultisigVotes::<T>::get((core_id, call_hash, account)) {
+            Some(previous_vote) => tally.remove_vote(previous_vote),
+            None => tally.voters.saturating_inc(),
+        }
+
+        tally.add_vote(vote);
+
+        tally
+    }
+
+    fn add_vote(&mut self, vote: Vote<Votes<T>>) {
+        match vote {
+            Vote::Aye(v) => self.ayes.saturating_accrue(v),
+            Vote::Nay(v) => self.nays.saturating_accrue(v),
+        }
+    }
+
+    fn remove_vote(&mut self, vote: Vote<Votes<T>>) {
+        match vote {
+            Vote::Aye(v) => self.ayes.saturating_reduce(v),
+            Vote::Nay(v) => self.nays.saturating_reduce(v),
+        }
+    }
 }
 
 impl<T: Config> VoteTally<Votes<T>, Core<T>> for Tally<T> {
@@ -99,7 +143,7 @@ impl<T: Config> VoteTally<Votes<T>, Core<T>> for Tally<T> {
         Self {
             ayes: Zero::zero(),
             nays: Zero::zero(),
-            records: BoundedBTreeMap::default(),
+            voters: 0,
             dummy: PhantomData,
         }
     }
@@ -236,4 +280,15 @@ where
     pub fn minimum_support_and_required_approval(core_id: T::CoreId) -> Option<(Perbill, Perbill)> {
         CoreStorage::<T>::get(core_id).map(|core| (core.minimum_support, core.required_approval))
     }
+
+    /// Checks if `voters` members voting on a proposal meet the core's minimum voters requirement.
+    pub fn minimum_voters_reached(core_id: T::CoreId, voters: u32) -> bool {
+        match CoreStorage::<T>::get(core_id).and_then(|core| core.minimum_voters) {
+            Some(MinimumVoters::Absolute(minimum)) => voters >= minimum,
+            Some(MinimumVoters::Proportion(minimum)) => {
+                Perbill::from_rational(voters, CoreMemberCount::<T>::get(core_id).max(1)) >= minimum
+            }
+            None => true,
+        }
+    }
 }
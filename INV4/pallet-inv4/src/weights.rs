@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `anny.local`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: Only the calls that existed at the time of this run were benchmarked. The weights marked as hand-written
+//! below are estimates based on their storage accesses and must be replaced by running the benchmarks again.
 
 // Executed Command:
 // ./target/release/tinkernet-collator
@@ -40,6 +43,27 @@ pub trait WeightInfo {
 	fn vote_multisig() -> Weight;
 	fn withdraw_vote_multisig() -> Weight;
 	fn cancel_multisig_proposal() -> Weight;
+	fn set_transfer_whitelist() -> Weight;
+	fn invite_member() -> Weight;
+	fn accept_invitation() -> Weight;
+	fn decline_invitation() -> Weight;
+	fn clear_expired_invitation() -> Weight;
+	fn open_token_sale() -> Weight;
+	fn close_token_sale() -> Weight;
+	fn buy_tokens() -> Weight;
+	fn token_mint_vested() -> Weight;
+	fn vest() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn set_proposal_limits() -> Weight;
+	fn amend_multisig_metadata(m: u32, ) -> Weight;
+	fn execute_multisig() -> Weight;
+	fn clear_multisig_votes(n: u32, ) -> Weight;
+	fn as_derivative() -> Weight;
+	fn set_remote_member() -> Weight;
+	fn vote_multisig_remote() -> Weight;
+	fn register_handle() -> Weight;
+	fn transfer_handle() -> Weight;
+	fn release_handle() -> Weight;
 }
 
 /// Weights for `pallet_inv4` using the Substrate node and recommended hardware.
@@ -58,7 +82,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `INV4::CoreByAccount` (r:0 w:1)
 	/// Proof: `INV4::CoreByAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreStorage` (r:0 w:1)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreMembers` (r:0 w:1)
 	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10000]`.
@@ -74,7 +98,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `INV4::CoreStorage` (r:1 w:1)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10000]`.
 	fn set_parameters(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -124,70 +148,339 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
 	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreStorage` (r:1 w:0)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
 	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::Multisig` (r:1 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:2 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10000]`.
 	/// The range of component `z` is `[0, 51190]`.
 	fn operate_multisig(m: u32, z: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `529`
-		//  Estimated: `554807`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(21_624_412, 554807)
+		// Measured before votes were moved to `MultisigVotes`, storage accesses adjusted by hand.
+		Weight::from_parts(21_624_412, 64970)
 			// Standard Error: 15
 			.saturating_add(Weight::from_parts(397, 0).saturating_mul(m.into()))
 			// Standard Error: 3
 			.saturating_add(Weight::from_parts(1_514, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `INV4::Multisig` (r:1 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
 	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
 	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreStorage` (r:1 w:0)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
 	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	fn vote_multisig() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `780`
-		//  Estimated: `554807`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 554807)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Measured before votes were moved to `MultisigVotes`, storage accesses adjusted by hand.
+		Weight::from_parts(26_000_000, 64970)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `INV4::Multisig` (r:1 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	fn withdraw_vote_multisig() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `514`
-		//  Estimated: `554807`
-		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 554807)
+		// Measured before votes were moved to `MultisigVotes`, storage accesses adjusted by hand.
+		Weight::from_parts(14_000_000, 64970)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::OpenProposals` (r:1 w:1)
+	/// Proof: `INV4::OpenProposals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::LastProposal` (r:1 w:1)
+	/// Proof: `INV4::LastProposal` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalDeposits` (r:1 w:1)
+	/// Proof: `INV4::ProposalDeposits` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreProposalLimits` (r:1 w:0)
+	/// Proof: `INV4::CoreProposalLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Dependents` (r:6 w:5)
+	/// Proof: `INV4::Dependents` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalResults` (r:0 w:6)
+	/// Proof: `INV4::ProposalResults` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchiveCount` (r:1 w:1)
+	/// Proof: `INV4::ProposalArchiveCount` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchive` (r:0 w:1)
+	/// Proof: `INV4::ProposalArchive` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	fn cancel_multisig_proposal() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(40_000_000, 67517)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TransferWhitelist` (r:0 w:1)
+	/// Proof: `INV4::TransferWhitelist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_transfer_whitelist() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(9_000_000, 13529)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn invite_member() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn accept_invitation() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn decline_invitation() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(11_000_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn clear_expired_invitation() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(11_000_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSales` (r:1 w:1)
+	/// Proof: `INV4::TokenSales` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn open_token_sale() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3574)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSales` (r:1 w:1)
+	/// Proof: `INV4::TokenSales` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn close_token_sale() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3574)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSales` (r:1 w:1)
+	/// Proof: `INV4::TokenSales` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn buy_tokens() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(1276), added: 3751, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn token_mint_vested() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(35_000_000, 4741)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(1276), added: 3751, mode: `MaxEncodedLen`)
+	fn vest() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(16_000_000, 4741)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:1)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_max_supply() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(13_000_000, 13550)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `INV4::Multisig` (r:0 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
-	fn cancel_multisig_proposal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(7_000_000, 0)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreProposalLimits` (r:0 w:1)
+	/// Proof: `INV4::CoreProposalLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn set_proposal_limits() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(11_000_000, 13555)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10000]`.
+	fn amend_multisig_metadata(m: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(22_000_000, 64970)
+			.saturating_add(Weight::from_parts(1_100, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalResults` (r:5 w:1)
+	/// Proof: `INV4::ProposalResults` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Dependents` (r:6 w:5)
+	/// Proof: `INV4::Dependents` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchiveCount` (r:1 w:1)
+	/// Proof: `INV4::ProposalArchiveCount` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchive` (r:0 w:1)
+	/// Proof: `INV4::ProposalArchive` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	fn execute_multisig() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(45_000_000, 65130)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `INV4::Multisig` (r:1 w:0)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1001 w:1000)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_multisig_votes(n: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 65130)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(n.into()))
+	}
+	fn as_derivative() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(5_000_000, 0)
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::RemoteMembers` (r:0 w:1)
+	/// Proof: `INV4::RemoteMembers` (`max_values`: None, `max_size`: Some(687), added: 3162, mode: `MaxEncodedLen`)
+	fn set_remote_member() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 13529)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::RemoteMembers` (r:1 w:0)
+	/// Proof: `INV4::RemoteMembers` (`max_values`: None, `max_size`: Some(687), added: 3162, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	fn vote_multisig_remote() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(29_000_000, 64970)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreHandles` (r:1 w:1)
+	/// Proof: `INV4::CoreHandles` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::HandleOwners` (r:1 w:1)
+	/// Proof: `INV4::HandleOwners` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn register_handle() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(25_000_000, 13529)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreHandles` (r:2 w:2)
+	/// Proof: `INV4::CoreHandles` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::HandleOwners` (r:0 w:1)
+	/// Proof: `INV4::HandleOwners` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn transfer_handle() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(34_000_000, 13529)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::CoreHandles` (r:1 w:1)
+	/// Proof: `INV4::CoreHandles` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::HandleOwners` (r:0 w:1)
+	/// Proof: `INV4::HandleOwners` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn release_handle() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -205,7 +498,7 @@ impl WeightInfo for () {
 	/// Storage: `INV4::CoreByAccount` (r:0 w:1)
 	/// Proof: `INV4::CoreByAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreStorage` (r:0 w:1)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreMembers` (r:0 w:1)
 	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10000]`.
@@ -221,7 +514,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `INV4::CoreStorage` (r:1 w:1)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10000]`.
 	fn set_parameters(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -271,68 +564,337 @@ impl WeightInfo for () {
 	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
 	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreStorage` (r:1 w:0)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
 	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::Multisig` (r:1 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:2 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10000]`.
 	/// The range of component `z` is `[0, 51190]`.
 	fn operate_multisig(m: u32, z: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `529`
-		//  Estimated: `554807`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(21_624_412, 554807)
+		// Measured before votes were moved to `MultisigVotes`, storage accesses adjusted by hand.
+		Weight::from_parts(21_624_412, 64970)
 			// Standard Error: 15
 			.saturating_add(Weight::from_parts(397, 0).saturating_mul(m.into()))
 			// Standard Error: 3
 			.saturating_add(Weight::from_parts(1_514, 0).saturating_mul(z.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `INV4::Multisig` (r:1 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
 	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
 	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `INV4::CoreStorage` (r:1 w:0)
-	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10063), added: 12538, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
 	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
 	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	fn vote_multisig() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `780`
-		//  Estimated: `554807`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 554807)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Measured before votes were moved to `MultisigVotes`, storage accesses adjusted by hand.
+		Weight::from_parts(26_000_000, 64970)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `INV4::Multisig` (r:1 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	fn withdraw_vote_multisig() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `514`
-		//  Estimated: `554807`
-		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 554807)
+		// Measured before votes were moved to `MultisigVotes`, storage accesses adjusted by hand.
+		Weight::from_parts(14_000_000, 64970)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::OpenProposals` (r:1 w:1)
+	/// Proof: `INV4::OpenProposals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::LastProposal` (r:1 w:1)
+	/// Proof: `INV4::LastProposal` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalDeposits` (r:1 w:1)
+	/// Proof: `INV4::ProposalDeposits` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreProposalLimits` (r:1 w:0)
+	/// Proof: `INV4::CoreProposalLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Dependents` (r:6 w:5)
+	/// Proof: `INV4::Dependents` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalResults` (r:0 w:6)
+	/// Proof: `INV4::ProposalResults` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchiveCount` (r:1 w:1)
+	/// Proof: `INV4::ProposalArchiveCount` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchive` (r:0 w:1)
+	/// Proof: `INV4::ProposalArchive` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	fn cancel_multisig_proposal() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(40_000_000, 67517)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::TransferWhitelist` (r:0 w:1)
+	/// Proof: `INV4::TransferWhitelist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_transfer_whitelist() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(9_000_000, 13529)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn invite_member() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn accept_invitation() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn decline_invitation() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(11_000_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::Invitations` (r:1 w:1)
+	/// Proof: `INV4::Invitations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn clear_expired_invitation() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(11_000_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSales` (r:1 w:1)
+	/// Proof: `INV4::TokenSales` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn open_token_sale() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3574)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSales` (r:1 w:1)
+	/// Proof: `INV4::TokenSales` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn close_token_sale() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3574)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::TokenSales` (r:1 w:1)
+	/// Proof: `INV4::TokenSales` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn buy_tokens() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(1276), added: 3751, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:1)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:1)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreMembers` (r:0 w:1)
+	/// Proof: `INV4::CoreMembers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn token_mint_vested() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(35_000_000, 4741)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::VestingSchedules` (r:1 w:1)
+	/// Proof: `INV4::VestingSchedules` (`max_values`: None, `max_size`: Some(1276), added: 3751, mode: `MaxEncodedLen`)
+	fn vest() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(16_000_000, 4741)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:1)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_max_supply() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(13_000_000, 13550)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `INV4::Multisig` (r:0 w:1)
-	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(551342), added: 553817, mode: `MaxEncodedLen`)
-	fn cancel_multisig_proposal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(7_000_000, 0)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreProposalLimits` (r:0 w:1)
+	/// Proof: `INV4::CoreProposalLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn set_proposal_limits() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(11_000_000, 13555)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10000]`.
+	fn amend_multisig_metadata(m: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(22_000_000, 64970)
+			.saturating_add(Weight::from_parts(1_100, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalResults` (r:5 w:1)
+	/// Proof: `INV4::ProposalResults` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Dependents` (r:6 w:5)
+	/// Proof: `INV4::Dependents` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchiveCount` (r:1 w:1)
+	/// Proof: `INV4::ProposalArchiveCount` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::ProposalArchive` (r:0 w:1)
+	/// Proof: `INV4::ProposalArchive` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	fn execute_multisig() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(45_000_000, 65130)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `INV4::Multisig` (r:1 w:0)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1001 w:1000)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_multisig_votes(n: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 65130)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(n.into()))
+	}
+	fn as_derivative() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(5_000_000, 0)
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::RemoteMembers` (r:0 w:1)
+	/// Proof: `INV4::RemoteMembers` (`max_values`: None, `max_size`: Some(687), added: 3162, mode: `MaxEncodedLen`)
+	fn set_remote_member() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(10_000_000, 13529)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `INV4::RemoteMembers` (r:1 w:0)
+	/// Proof: `INV4::RemoteMembers` (`max_values`: None, `max_size`: Some(687), added: 3162, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::Multisig` (r:1 w:1)
+	/// Proof: `INV4::Multisig` (`max_values`: None, `max_size`: Some(61505), added: 63980, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::Accounts` (r:1 w:0)
+	/// Proof: `CoreAssets::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `CoreAssets::TotalIssuance` (r:1 w:0)
+	/// Proof: `CoreAssets::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::MultisigVotes` (r:1 w:1)
+	/// Proof: `INV4::MultisigVotes` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	fn vote_multisig_remote() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(29_000_000, 64970)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreHandles` (r:1 w:1)
+	/// Proof: `INV4::CoreHandles` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::HandleOwners` (r:1 w:1)
+	/// Proof: `INV4::HandleOwners` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn register_handle() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(25_000_000, 13529)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `INV4::CoreStorage` (r:1 w:0)
+	/// Proof: `INV4::CoreStorage` (`max_values`: None, `max_size`: Some(10090), added: 12565, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::CoreHandles` (r:2 w:2)
+	/// Proof: `INV4::CoreHandles` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::HandleOwners` (r:0 w:1)
+	/// Proof: `INV4::HandleOwners` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn transfer_handle() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(34_000_000, 13529)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `INV4::CoreHandles` (r:1 w:1)
+	/// Proof: `INV4::CoreHandles` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `INV4::HandleOwners` (r:0 w:1)
+	/// Proof: `INV4::HandleOwners` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn release_handle() -> Weight {
+		// Hand-written estimate, not benchmarked yet.
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -0,0 +1,113 @@
+//! XCM Location Conversion.
+//!
+//! ## Overview
+//!
+//! Cores are described in XCM as a `Plurality { id: BodyId::Index(core_id), part: BodyPart::Voice }` within the
+//! parachain, see [`crate::account_derivation`]. This module provides the converters runtimes plug into their
+//! `xcm_executor::Config` so messages sent by a core are executed as that core.
+//!
+//! ### Core functionalities:
+//! - `CoreLocationToAccountId`: Converts the location of a core, or of one of its derivative sub-accounts, into
+//!   the same account it has in its own chain. Meant to be added to the runtime's `LocationToAccountId`.
+//! - `CoreOriginConverter`: Converts the location of a core of this chain with `OriginKind::Native` into a core
+//!   origin. Meant to be added to the runtime's `OriginConverter`.
+//!
+//! Cores of sibling chains are only ever converted into their account, through `CoreLocationToAccountId`. They
+//! never act as core origins in this chain, as their voting happens, and is enforced, in their own chain.
+
+use crate::{
+    account_derivation::{core_body_index, CoreDescription},
+    origin::{INV4Origin, MultisigInternalOrigin},
+    Config,
+};
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use xcm::v3::{Junction, Junctions, MultiLocation, OriginKind};
+use xcm_builder::HashedDescription;
+use xcm_executor::traits::{ConvertLocation, ConvertOrigin};
+
+/// Converts the location of a core into its account, matching the account derived in the core's own chain.
+///
+/// Cores of sibling chains are described as `(1, Parachain(para_id), Plurality)` and local cores as
+/// `(0, Plurality)`, the latter using the pallet's `ParaId`. In both cases a trailing `GeneralIndex` describes one
+/// of the core's derivative sub-accounts. Accounts are derived by xcm-builder's [`HashedDescription`] of the
+/// [`CoreDescription`], the same derivation as [`crate::account_derivation::CoreAccountDerivation`].
+pub struct CoreLocationToAccountId<T>(PhantomData<T>);
+
+impl<T: Config> ConvertLocation<T::AccountId> for CoreLocationToAccountId<T>
+where
+    T::AccountId: From<[u8; 32]>,
+{
+    fn convert_location(location: &MultiLocation) -> Option<T::AccountId> {
+        let location = match location {
+            // Local cores are described from the perspective of a sibling chain, so they keep the same account.
+            MultiLocation {
+                parents: 0,
+                interior,
+            } => MultiLocation {
+                parents: 1,
+                interior: interior
+                    .pushed_front_with(Junction::Parachain(T::ParaId::get()))
+                    .ok()?,
+            },
+
+            MultiLocation {
+                parents: 1,
+                interior,
+            } if matches!(interior.first(), Some(Junction::Parachain(_))) => *location,
+
+            _ => return None,
+        };
+
+        HashedDescription::<T::AccountId, CoreDescription>::convert_location(&location)
+    }
+}
+
+/// Converts the location of a core into a core origin, so cross-chain messages can dispatch calls as the core.
+///
+/// Only `OriginKind::Native` origins of cores of the chain set as the pallet's `ParaId` are converted, either from
+/// `(1, Parachain(para_id), Plurality)` or locally from `(0, Plurality)`. The origin carries zero support, so calls
+/// requiring a stricter threshold than the core's voting rules are rejected.
+///
+/// Cores of any other chain, including siblings running this pallet, are rejected here: they map to their account
+/// through [`CoreLocationToAccountId`] only, never to a core origin.
+pub struct CoreOriginConverter<T, RuntimeOrigin>(PhantomData<(T, RuntimeOrigin)>);
+
+impl<T: Config, RuntimeOrigin> ConvertOrigin<RuntimeOrigin>
+    for CoreOriginConverter<T, RuntimeOrigin>
+where
+    T::AccountId: From<[u8; 32]>,
+    RuntimeOrigin: From<INV4Origin<T>>,
+{
+    fn convert_origin(
+        origin: impl Into<MultiLocation>,
+        kind: OriginKind,
+    ) -> Result<RuntimeOrigin, MultiLocation> {
+        let origin = origin.into();
+
+        if kind != OriginKind::Native {
+            return Err(origin);
+        }
+
+        let core_index = match origin {
+            MultiLocation {
+                parents: 1,
+                interior: Junctions::X2(Junction::Parachain(para_id), ref body),
+            } if para_id == T::ParaId::get() => core_body_index(body),
+
+            MultiLocation {
+                parents: 0,
+                interior: Junctions::X1(ref body),
+            } => core_body_index(body),
+
+            _ => None,
+        };
+
+        match core_index {
+            Some(core_index) => {
+                Ok(INV4Origin::Multisig(MultisigInternalOrigin::new(core_index.into())).into())
+            }
+            None => Err(origin),
+        }
+    }
+}
@@ -4,17 +4,13 @@ use super::*;
 use crate::Pallet as OcifStaking;
 use core::ops::Add;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::{
-    traits::{Get, OnFinalize, OnInitialize},
-    BoundedVec,
-};
+use frame_support::traits::{Get, OnFinalize, OnInitialize};
 use frame_system::{Pallet as System, RawOrigin};
 use pallet_inv4::{
     account_derivation::CoreAccountDerivation,
     origin::{INV4Origin, MultisigInternalOrigin},
 };
 use sp_runtime::traits::{Bounded, One};
-use sp_std::vec;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
@@ -52,9 +48,6 @@ where
 
     OcifStaking::<T>::register_core(
         INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())).into(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
     )
 }
 
@@ -72,9 +65,6 @@ where
 
     OcifStaking::<T>::register_core(
         INV4Origin::Multisig(MultisigInternalOrigin::new(1u32.into())).into(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
-        vec![].try_into().unwrap(),
     )
 }
 
@@ -124,50 +114,14 @@ benchmarks! {
 }
 
     register_core {
-        let n in 0 .. T::MaxNameLength::get();
-        let d in 0 .. T::MaxDescriptionLength::get();
-        let i in 0 .. T::MaxImageUrlLength::get();
-
-        let name: BoundedVec<u8, T::MaxNameLength> = vec![u8::MAX; n as usize].try_into().unwrap();
-        let description: BoundedVec<u8, T::MaxDescriptionLength> = vec![u8::MAX; d as usize].try_into().unwrap();
-        let image: BoundedVec<u8, T::MaxImageUrlLength> = vec![u8::MAX; i as usize].try_into().unwrap();
-
         <T as Config>::Currency::make_free_balance_be(&derive_account::<T>(0u32.into()), T::RegisterDeposit::get() + T::RegisterDeposit::get());
-    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), name, description, image)
+    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())))
     verify {
         assert_last_event::<T>(Event::<T>::CoreRegistered {
             core: 0u32.into()
         }.into());
     }
 
-    change_core_metadata {
-        let n in 0 .. T::MaxNameLength::get();
-        let d in 0 .. T::MaxDescriptionLength::get();
-        let i in 0 .. T::MaxImageUrlLength::get();
-
-        let name: BoundedVec<u8, T::MaxNameLength> = vec![u8::MAX; n as usize].try_into().unwrap();
-        let description: BoundedVec<u8, T::MaxDescriptionLength> = vec![u8::MAX; d as usize].try_into().unwrap();
-        let image: BoundedVec<u8, T::MaxImageUrlLength> = vec![u8::MAX; i as usize].try_into().unwrap();
-
-        mock_register().unwrap();
-
-    }: _(INV4Origin::Multisig(MultisigInternalOrigin::new(0u32.into())), name.clone(), description.clone(), image.clone())
-        verify {
-            assert_last_event::<T>(Event::<T>::MetadataChanged {
-                core: 0u32.into(),
-                old_metadata: CoreMetadata {
-                    name: vec![],
-                    description: vec![],
-                    image: vec![]
-                },
-                new_metadata: CoreMetadata {
-                    name: name.to_vec(),
-                    description: description.to_vec(),
-                    image: image.to_vec()
-                }
-            }.into());
-        }
-
     unregister_core {
         mock_register().unwrap();
 
@@ -31,13 +31,14 @@
 //! * `RewardRatio` - Defines the ratio of balance from the pot to distribute to Cores and Stakers, respectively.
 //! * `StakeThresholdForActiveCore` - Defines the threshold of stake a Core needs to surpass to become active.
 //!
+//! The name, description and image of a Core are not kept by this pallet, they are read from the Core's metadata in INV4.
+//!
 //! **Example Runtime implementation can be found in [src/testing/mock.rs](./src/testing/mock.rs)**
 //!
 //! ## Dispatchable Functions
 //!
 //! * `register_core` - Registers a Core in the system.
 //! * `unregister_core` - Unregisters a Core from the system, starting the unbonding period for the Stakers.
-//! * `change_core_metadata` - Changes the metadata tied to a Core.
 //! * `stake` - Stakes tokens towards a Core.
 //! * `unstake` - Unstakes tokens from a core and starts the unbonding period for those tokens.
 //! * `withdraw_unstaked` - Withdraws tokens that have already been through the unbonding period.
@@ -77,6 +78,7 @@ use primitives::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod testing;
 pub mod weights;
@@ -103,7 +105,10 @@ pub mod pallet {
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     /// The opaque token type for an imbalance. This is returned by unbalanced operations and must be dealt with.
@@ -111,15 +116,8 @@ pub mod pallet {
         <T as frame_system::Config>::AccountId,
     >>::NegativeImbalance;
 
-    /// The core metadata type of this pallet.
-    pub type CoreMetadataOf<T> = CoreMetadata<
-        BoundedVec<u8, <T as Config>::MaxNameLength>,
-        BoundedVec<u8, <T as Config>::MaxDescriptionLength>,
-        BoundedVec<u8, <T as Config>::MaxImageUrlLength>,
-    >;
-
-    /// The core information type, containing a core's AccountId and CoreMetadataOf.
-    pub type CoreInfoOf<T> = CoreInfo<<T as frame_system::Config>::AccountId, CoreMetadataOf<T>>;
+    /// The core information type, containing a core's AccountId.
+    pub type CoreInfoOf<T> = CoreInfo<<T as frame_system::Config>::AccountId>;
 
     /// Alias type for the era identifier type.
     pub type Era = u32;
@@ -198,18 +196,6 @@ pub mod pallet {
         #[pallet::constant]
         type StakeThresholdForActiveCore: Get<BalanceOf<Self>>;
 
-        /// Maximum length of a core's name.
-        #[pallet::constant]
-        type MaxNameLength: Get<u32>;
-
-        /// Maximum length of a core's description.
-        #[pallet::constant]
-        type MaxDescriptionLength: Get<u32>;
-
-        /// Maximum length of a core's image URL.
-        #[pallet::constant]
-        type MaxImageUrlLength: Get<u32>;
-
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
 
@@ -353,13 +339,6 @@ pub mod pallet {
         /// Halt status changed.
         HaltChanged { is_halted: bool },
 
-        /// Core metadata changed.
-        MetadataChanged {
-            core: T::CoreId,
-            old_metadata: CoreMetadata<Vec<u8>, Vec<u8>, Vec<u8>>,
-            new_metadata: CoreMetadata<Vec<u8>, Vec<u8>, Vec<u8>>,
-        },
-
         /// Staker moved an amount of stake to another core.
         StakeMoved {
             staker: T::AccountId,
@@ -478,23 +457,10 @@ pub mod pallet {
         ///
         /// As part of this call, `RegisterDeposit` will be reserved from the core account.
         ///
-        /// - `name`: Name of the core.
-        /// - `description`: Description of the core.
-        /// - `image`: Image URL of the core.
+        /// The core's name, description and image are the ones set in its INV4 metadata.
         #[pallet::call_index(0)]
-        #[pallet::weight(
-            <T as Config>::WeightInfo::register_core(
-                name.len() as u32,
-                description.len() as u32,
-                image.len() as u32
-            )
-        )]
-        pub fn register_core(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, T::MaxNameLength>,
-            description: BoundedVec<u8, T::MaxDescriptionLength>,
-            image: BoundedVec<u8, T::MaxImageUrlLength>,
-        ) -> DispatchResultWithPostInfo {
+        #[pallet::weight(<T as Config>::WeightInfo::register_core())]
+        pub fn register_core(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             Self::ensure_not_halted()?;
 
             let core = ensure_multisig::<T, OriginFor<T>>(origin)?;
@@ -506,19 +472,12 @@ pub mod pallet {
                 Error::<T>::CoreAlreadyRegistered,
             );
 
-            let metadata: CoreMetadataOf<T> = CoreMetadata {
-                name,
-                description,
-                image,
-            };
-
             <T as pallet::Config>::Currency::reserve(&core_account, T::RegisterDeposit::get())?;
 
             RegisteredCore::<T>::insert(
                 core_id,
                 CoreInfo {
                     account: core_account,
-                    metadata,
                 },
             );
 
@@ -608,65 +567,6 @@ pub mod pallet {
             Ok(Some(<T as Config>::WeightInfo::unregister_core()).into())
         }
 
-        /// Used to change the metadata of a core.
-        ///
-        /// The origin has to be the core origin.
-        ///
-        /// - `name`: Name of the core.
-        /// - `description`: Description of the core.
-        /// - `image`: Image URL of the core.
-        #[pallet::call_index(2)]
-        #[pallet::weight(
-            <T as Config>::WeightInfo::change_core_metadata(
-                name.len() as u32,
-                description.len() as u32,
-                image.len() as u32
-            )
-        )]
-        pub fn change_core_metadata(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, T::MaxNameLength>,
-            description: BoundedVec<u8, T::MaxDescriptionLength>,
-            image: BoundedVec<u8, T::MaxImageUrlLength>,
-        ) -> DispatchResultWithPostInfo {
-            Self::ensure_not_halted()?;
-
-            let core_origin = ensure_multisig::<T, OriginFor<T>>(origin)?;
-            let core_id = core_origin.id;
-
-            RegisteredCore::<T>::try_mutate(core_id, |core| {
-                let mut new_core = core.take().ok_or(Error::<T>::NotRegistered)?;
-
-                let new_metadata: CoreMetadataOf<T> = CoreMetadata {
-                    name: name.clone(),
-                    description: description.clone(),
-                    image: image.clone(),
-                };
-
-                let old_metadata = new_core.metadata;
-
-                new_core.metadata = new_metadata;
-
-                *core = Some(new_core);
-
-                Self::deposit_event(Event::<T>::MetadataChanged {
-                    core: core_id,
-                    old_metadata: CoreMetadata {
-                        name: old_metadata.name.into_inner(),
-                        description: old_metadata.description.into_inner(),
-                        image: old_metadata.image.into_inner(),
-                    },
-                    new_metadata: CoreMetadata {
-                        name: name.to_vec(),
-                        description: description.to_vec(),
-                        image: image.to_vec(),
-                    },
-                });
-
-                Ok(().into())
-            })
-        }
-
         /// Lock up and stake balance of the origin account.
         ///
         /// `value` must be more than the `minimum_stake` specified by `MinimumStakingAmount`
@@ -1206,6 +1106,17 @@ pub mod pallet {
             free_balance.saturating_sub(ledger.locked)
         }
 
+        /// Returns the metadata of a registered core, as set in INV4.
+        pub fn core_metadata(
+            core_id: T::CoreId,
+        ) -> Option<pallet_inv4::inv4_core::CoreMetadataOf<T>> {
+            if !RegisteredCore::<T>::contains_key(core_id) {
+                return None;
+            }
+
+            pallet_inv4::Pallet::<T>::core_storage(core_id).map(|core| core.metadata)
+        }
+
         /// Returns total value locked by staking.
         ///
         /// Note that this can differ from _total staked value_ since some funds might be undergoing the unbonding period.
@@ -0,0 +1,141 @@
+use super::*;
+use frame_support::{
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use log::{info, warn};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+    use super::*;
+    use codec::{Decode, Encode};
+
+    /// `CoreMetadata` as stored by this pallet before v1.
+    #[derive(Encode, Decode)]
+    pub struct OldCoreMetadata {
+        pub name: Vec<u8>,
+        pub description: Vec<u8>,
+        pub image: Vec<u8>,
+    }
+
+    /// `CoreInfo` as stored before v1, with its own copy of the core metadata.
+    #[derive(Encode, Decode)]
+    pub struct OldCoreInfo<AccountId> {
+        pub account: AccountId,
+        pub metadata: OldCoreMetadata,
+    }
+
+    /// Fills `field` with `old` if it's still empty, returning whether any of `old` was discarded.
+    fn merge_field<S: Get<u32>>(field: &mut BoundedVec<u8, S>, old: Vec<u8>) -> bool {
+        if old.is_empty() {
+            return false;
+        }
+
+        if !field.is_empty() {
+            return true;
+        }
+
+        let len = old.len();
+        *field = BoundedVec::truncate_from(old);
+
+        field.len() < len
+    }
+
+    /// Drops the metadata of the registered cores, filling the fields that are still empty in their INV4 metadata.
+    ///
+    /// Must run after INV4's `MigrateToV3`.
+    pub fn migrate_registered_cores<T: Config>() -> u64 {
+        let mut translated = 0u64;
+
+        RegisteredCore::<T>::translate::<OldCoreInfo<T::AccountId>, _>(|core_id, old| {
+            translated += 1;
+
+            let OldCoreMetadata {
+                name,
+                description,
+                image,
+            } = old.metadata;
+
+            let discarded = pallet_inv4::CoreStorage::<T>::mutate(core_id, |core| match core {
+                Some(core) => {
+                    let metadata = &mut core.metadata;
+
+                    merge_field(&mut metadata.name, name)
+                        | merge_field(&mut metadata.description, description)
+                        | merge_field(&mut metadata.image, image)
+                }
+                None => !(name.is_empty() && description.is_empty() && image.is_empty()),
+            });
+
+            if discarded {
+                warn!(
+                    "Discarding OCIF metadata of core {:?} not fitting in its INV4 metadata",
+                    core_id
+                );
+            }
+
+            Some(CoreInfo {
+                account: old.account,
+            })
+        });
+
+        translated
+    }
+
+    /// Moves the metadata of the registered cores into INV4.
+    ///
+    /// INV4's metadata takes precedence: each OCIF field only fills the matching INV4 field if it's still empty
+    /// after INV4's `MigrateToV3`, which fills the description from the old INV4 metadata. OCIF fields that
+    /// don't fit, because the INV4 field is already set or the value exceeds its length limit, are discarded
+    /// with a warning naming the core.
+    pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 0,
+                "Required v0 before upgrading to v1"
+            );
+
+            frame_support::ensure!(
+                pallet_inv4::Pallet::<T>::on_chain_storage_version() >= 3,
+                "Required INV4 v3 before upgrading to v1"
+            );
+
+            Ok((RegisteredCore::<T>::iter_keys().count() as u64).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() == 0 {
+                let translated = migrate_registered_cores::<T>();
+
+                StorageVersion::new(1).put::<Pallet<T>>();
+
+                info!("v1 applied successfully");
+                T::DbWeight::get().reads_writes(translated * 2 + 1, translated * 2 + 1)
+            } else {
+                warn!("Skipping v1, should be removed");
+                T::DbWeight::get().reads(1)
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "v1 not applied"
+            );
+
+            let cores_before = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed decoding the pre upgrade state")?;
+
+            frame_support::ensure!(
+                RegisteredCore::<T>::iter_values().count() as u64 == cores_before,
+                "Not all cores were translated"
+            );
+
+            Ok(())
+        }
+    }
+}
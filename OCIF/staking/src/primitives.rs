@@ -7,8 +7,7 @@
 //! ## Types overview:
 //!
 //! - `BalanceOf` - A type alias for the balance of a currency in the system.
-//! - `CoreMetadata` - A struct that holds metadata for a core entity in the system.
-//! - `CoreInfo` - A struct that holds information about a core entity, including its account ID and metadata.
+//! - `CoreInfo` - A struct that holds information about a core entity, including its account ID.
 //! - `RewardInfo` - A struct that holds information about rewards, including the balance for stakers and the core.
 //! - `EraInfo` - A struct that holds information about a specific era, including rewards, staked balance, active stake, and locked balance.
 //! - `CoreStakeInfo` - A struct that holds information about a core's stake, including the total balance,
@@ -42,19 +41,12 @@ pub type BalanceOf<T> =
 
 const MAX_ASSUMED_VEC_LEN: u32 = 10;
 
-/// Metadata for a core entity in the system.
+/// Information about a core entity, including its account ID.
+///
+/// The metadata of the core is kept by INV4, see [`Pallet::core_metadata`].
 #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct CoreMetadata<Name, Description, Image> {
-    pub name: Name,
-    pub description: Description,
-    pub image: Image,
-}
-
-/// Information about a core entity, including its account ID and metadata.
-#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct CoreInfo<AccountId, Metadata> {
+pub struct CoreInfo<AccountId> {
     pub account: AccountId,
-    pub metadata: Metadata,
 }
 
 /// Information about rewards, including the balance for stakers and the core.
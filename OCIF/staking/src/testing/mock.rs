@@ -7,7 +7,8 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     traits::{
-        fungibles::Credit, ConstU128, ConstU32, Contains, Currency, OnFinalize, OnInitialize,
+        fungibles::Credit, ConstU128, ConstU32, ConstU64, Contains, Currency, OnFinalize,
+        OnInitialize,
     },
     weights::{
         constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
@@ -234,7 +235,6 @@ impl pallet_inv4::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type RuntimeCall = RuntimeCall;
-    type MaxCallers = MaxCallers;
     type CoreSeedBalance = CoreSeedBalance;
     type AssetsProvider = CoreAssets;
     type RuntimeOrigin = RuntimeOrigin;
@@ -249,6 +249,18 @@ impl pallet_inv4::Config for Test {
 
     type ParaId = ConstU32<2125>;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type InvitationPeriod = ConstU64<100>;
+    type MaxVestingSchedules = ConstU32<10>;
+    type MaxDependencies = ConstU32<5>;
+    type MaxBatchCalls = ConstU32<10>;
+    type MaxArchivedProposals = ConstU32<10>;
+    type RemoteVoteOrigin = frame_support::traits::NeverEnsureOrigin<xcm::v3::MultiLocation>;
+    type MaxHandleLength = ConstU32<32>;
+    type HandleDeposit = ConstU128<1_000_000_000_000>;
+    type MaxNameLength = ConstU32<20>;
+    type MaxDescriptionLength = ConstU32<300>;
+    type MaxImageUrlLength = ConstU32<60>;
+    type MaxWebsiteLength = ConstU32<60>;
 }
 
 impl pallet_ocif_staking::Config for Test {
@@ -263,9 +275,6 @@ impl pallet_ocif_staking::Config for Test {
     type MaxUnlocking = MaxUnlocking;
     type UnbondingPeriod = UnbondingPeriod;
     type MaxEraStakeValues = MaxEraStakeValues;
-    type MaxDescriptionLength = ConstU32<300>;
-    type MaxNameLength = ConstU32<20>;
-    type MaxImageUrlLength = ConstU32<60>;
     type RewardRatio = RewardRatio;
     type StakeThresholdForActiveCore = ConstU128<THRESHOLD>;
     type WeightInfo = crate::weights::SubstrateWeight<Test>;
@@ -1,10 +1,15 @@
 use crate::{
-    pallet::{CoreMetadataOf, Error, Event},
+    pallet::{Error, Event},
     testing::*,
     *,
 };
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use mock::Balances;
+use pallet_inv4::inv4_core::CoreMetadataOf;
 use sp_runtime::{traits::Zero, Perbill};
 
 #[test]
@@ -228,10 +233,7 @@ fn register_twice_with_same_account_fails() {
         assert_noop!(
             OcifStaking::register_core(
                 pallet_inv4::Origin::Multisig(pallet_inv4::origin::MultisigInternalOrigin::new(A))
-                    .into(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap()
+                    .into()
             ),
             Error::<Test>::CoreAlreadyRegistered
         );
@@ -239,53 +241,102 @@ fn register_twice_with_same_account_fails() {
 }
 
 #[test]
-fn change_metadata() {
+fn core_metadata_is_read_from_inv4() {
     ExternalityBuilder::build().execute_with(|| {
         initialize_first_block();
 
         let core_id = A;
 
-        assert_register(core_id);
+        let mut metadata = CoreMetadataOf::<Test>::default();
+        metadata.name = b"Test CORE".to_vec().try_into().unwrap();
+        metadata.description = b"Description of the test CORE".to_vec().try_into().unwrap();
+        metadata.image = b"https://test.core".to_vec().try_into().unwrap();
 
-        System::assert_last_event(mock::RuntimeEvent::OcifStaking(Event::CoreRegistered {
-            core: core_id,
-        }));
+        pallet_inv4::CoreStorage::<Test>::insert(
+            core_id,
+            pallet_inv4::CoreInfoOf::<Test> {
+                account: account(core_id),
+                metadata: metadata.clone(),
+                minimum_support: Perbill::one(),
+                required_approval: Perbill::one(),
+                minimum_voters: None,
+                frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
+            },
+        );
+
+        // Cores that aren't registered have no metadata here.
+        assert_eq!(OcifStaking::core_metadata(core_id), None);
+
+        assert_register(core_id);
 
         assert_eq!(
             OcifStaking::core_info(core_id),
             Some(CoreInfo {
                 account: account(core_id),
-                metadata: CoreMetadata {
-                    name: BoundedVec::default(),
-                    description: BoundedVec::default(),
-                    image: BoundedVec::default()
-                }
             })
         );
+        assert_eq!(OcifStaking::core_metadata(core_id), Some(metadata));
+    })
+}
 
-        let new_metadata: CoreMetadataOf<Test> = CoreMetadata {
-            name: b"Test CORE".to_vec().try_into().unwrap(),
-            description: b"Description of the test CORE".to_vec().try_into().unwrap(),
-            image: b"https://test.core".to_vec().try_into().unwrap(),
-        };
-
-        assert_ok!(OcifStaking::change_core_metadata(
-            pallet_inv4::Origin::Multisig(pallet_inv4::origin::MultisigInternalOrigin::new(
-                core_id
-            ))
-            .into(),
-            b"Test CORE".to_vec().try_into().unwrap(),
-            b"Description of the test CORE".to_vec().try_into().unwrap(),
-            b"https://test.core".to_vec().try_into().unwrap(),
-        ));
+#[test]
+fn migrate_to_v1_moves_metadata_to_inv4() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut inv4_metadata = CoreMetadataOf::<Test>::default();
+        inv4_metadata.description = b"Description set in INV4".to_vec().try_into().unwrap();
+
+        pallet_inv4::CoreStorage::<Test>::insert(
+            A,
+            pallet_inv4::CoreInfoOf::<Test> {
+                account: account(A),
+                metadata: inv4_metadata,
+                minimum_support: Perbill::one(),
+                required_approval: Perbill::one(),
+                minimum_voters: None,
+                frozen_tokens: true,
+                members_only_transfers: false,
+                max_supply: None,
+                max_supply_supermajority: Perbill::one(),
+            },
+        );
+
+        frame_support::storage::unhashed::put_raw(
+            &RegisteredCore::<Test>::hashed_key_for(A),
+            &migrations::v1::OldCoreInfo {
+                account: account(A),
+                metadata: migrations::v1::OldCoreMetadata {
+                    name: b"Test CORE".to_vec(),
+                    description: b"Description set in staking".to_vec(),
+                    image: b"https://test.core".to_vec(),
+                },
+            }
+            .encode(),
+        );
+
+        StorageVersion::new(0).put::<OcifStaking>();
+
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OcifStaking::on_chain_storage_version(), 1);
 
         assert_eq!(
-            OcifStaking::core_info(core_id),
+            OcifStaking::core_info(A),
             Some(CoreInfo {
-                account: account(core_id),
-                metadata: new_metadata
+                account: account(A)
             })
         );
+
+        // Fields already set in INV4 are kept.
+        let metadata = OcifStaking::core_metadata(A).unwrap();
+        assert_eq!(metadata.name.to_vec(), b"Test CORE".to_vec());
+        assert_eq!(
+            metadata.description.to_vec(),
+            b"Description set in INV4".to_vec()
+        );
+        assert_eq!(metadata.image.to_vec(), b"https://test.core".to_vec());
     })
 }
 
@@ -1982,10 +2033,7 @@ fn pallet_halt_is_ok() {
                 pallet_inv4::Origin::Multisig(pallet_inv4::origin::MultisigInternalOrigin::new(
                     core_id
                 ))
-                .into(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap()
+                .into()
             ),
             Error::<Test>::Halted
         );
@@ -2000,19 +2048,6 @@ fn pallet_halt_is_ok() {
             Error::<Test>::Halted
         );
 
-        assert_noop!(
-            OcifStaking::change_core_metadata(
-                pallet_inv4::Origin::Multisig(pallet_inv4::origin::MultisigInternalOrigin::new(
-                    core_id
-                ))
-                .into(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap(),
-                Vec::default().try_into().unwrap()
-            ),
-            Error::<Test>::Halted
-        );
-
         assert_noop!(
             OcifStaking::withdraw_unstaked(RuntimeOrigin::signed(staker_account.clone())),
             Error::<Test>::Halted
@@ -32,8 +32,7 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_ocif_staking`.
 pub trait WeightInfo {
-	fn register_core(n: u32, d: u32, i: u32, ) -> Weight;
-	fn change_core_metadata(n: u32, d: u32, i: u32, ) -> Weight;
+	fn register_core() -> Weight;
 	fn unregister_core() -> Weight;
 	fn stake() -> Weight;
 	fn unstake() -> Weight;
@@ -52,13 +51,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:1)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `n` is `[0, 20]`.
-	/// The range of component `d` is `[0, 300]`.
-	/// The range of component `i` is `[0, 100]`.
-	fn register_core(_n: u32, _d: u32, _i: u32, ) -> Weight {
+	fn register_core() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `213`
 		//  Estimated: `3942`
@@ -69,32 +65,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:1)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
-	/// The range of component `n` is `[0, 20]`.
-	/// The range of component `d` is `[0, 300]`.
-	/// The range of component `i` is `[0, 100]`.
-	fn change_core_metadata(n: u32, d: u32, i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `86`
-		//  Estimated: `3942`
-		// Minimum execution time: 9_000_000 picoseconds.
-		Weight::from_parts(9_593_278, 3942)
-			// Standard Error: 890
-			.saturating_add(Weight::from_parts(4_471, 0).saturating_mul(n.into()))
-			// Standard Error: 61
-			.saturating_add(Weight::from_parts(856, 0).saturating_mul(d.into()))
-			// Standard Error: 182
-			.saturating_add(Weight::from_parts(1_028, 0).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: `OcifStaking::Halted` (r:1 w:0)
-	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:1)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:0)
@@ -127,7 +101,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
 	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
@@ -154,7 +128,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
@@ -251,7 +225,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:2 w:2)
@@ -276,13 +250,10 @@ impl WeightInfo for () {
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:1)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// The range of component `n` is `[0, 20]`.
-	/// The range of component `d` is `[0, 300]`.
-	/// The range of component `i` is `[0, 100]`.
-	fn register_core(_n: u32, _d: u32, _i: u32, ) -> Weight {
+	fn register_core() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `213`
 		//  Estimated: `3942`
@@ -293,32 +264,10 @@ impl WeightInfo for () {
 	}
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:1)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
-	/// The range of component `n` is `[0, 20]`.
-	/// The range of component `d` is `[0, 300]`.
-	/// The range of component `i` is `[0, 100]`.
-	fn change_core_metadata(n: u32, d: u32, i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `86`
-		//  Estimated: `3942`
-		// Minimum execution time: 9_000_000 picoseconds.
-		Weight::from_parts(9_593_278, 3942)
-			// Standard Error: 890
-			.saturating_add(Weight::from_parts(4_471, 0).saturating_mul(n.into()))
-			// Standard Error: 61
-			.saturating_add(Weight::from_parts(856, 0).saturating_mul(d.into()))
-			// Standard Error: 182
-			.saturating_add(Weight::from_parts(1_028, 0).saturating_mul(i.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: `OcifStaking::Halted` (r:1 w:0)
-	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:1)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:0)
@@ -351,7 +300,7 @@ impl WeightInfo for () {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::Ledger` (r:1 w:1)
 	/// Proof: `OcifStaking::Ledger` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
@@ -378,7 +327,7 @@ impl WeightInfo for () {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:1 w:1)
@@ -475,7 +424,7 @@ impl WeightInfo for () {
 	/// Storage: `OcifStaking::Halted` (r:1 w:0)
 	/// Proof: `OcifStaking::Halted` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::RegisteredCore` (r:1 w:0)
-	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(477), added: 2952, mode: `MaxEncodedLen`)
+	/// Proof: `OcifStaking::RegisteredCore` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::CurrentEra` (r:1 w:0)
 	/// Proof: `OcifStaking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `OcifStaking::GeneralStakerInfo` (r:2 w:2)
@@ -240,59 +240,6 @@ impl Contains<AccountId> for CoreDustRemovalWhitelist {
     }
 }
 
-pub struct DisallowIfFrozen;
-impl orml_traits2::currency::OnTransfer<AccountId, <Test as pallet_inv4::Config>::CoreId, Balance>
-    for DisallowIfFrozen
-{
-    fn on_transfer(
-        currency_id: <Test as pallet_inv4::Config>::CoreId,
-        _from: &AccountId,
-        _to: &AccountId,
-        _amount: Balance,
-    ) -> sp_runtime::DispatchResult {
-        if let Some(true) = INV4::is_asset_frozen(currency_id) {
-            Err(sp_runtime::DispatchError::Token(
-                sp_runtime::TokenError::Frozen,
-            ))
-        } else {
-            Ok(())
-        }
-    }
-}
-
-pub struct HandleNewMembers;
-impl orml_traits2::Happened<(AccountId, <Test as pallet_inv4::Config>::CoreId)>
-    for HandleNewMembers
-{
-    fn happened((member, core_id): &(AccountId, <Test as pallet_inv4::Config>::CoreId)) {
-        INV4::add_member(core_id, member)
-    }
-}
-
-pub struct HandleRemovedMembers;
-impl orml_traits2::Happened<(AccountId, <Test as pallet_inv4::Config>::CoreId)>
-    for HandleRemovedMembers
-{
-    fn happened((member, core_id): &(AccountId, <Test as pallet_inv4::Config>::CoreId)) {
-        INV4::remove_member(core_id, member)
-    }
-}
-
-pub struct INV4TokenHooks;
-impl
-    orml_traits2::currency::MutationHooks<AccountId, <Test as pallet_inv4::Config>::CoreId, Balance>
-    for INV4TokenHooks
-{
-    type PreTransfer = DisallowIfFrozen;
-    type OnDust = ();
-    type OnSlash = ();
-    type PreDeposit = ();
-    type PostDeposit = ();
-    type PostTransfer = ();
-    type OnNewTokenAccount = HandleNewMembers;
-    type OnKilledTokenAccount = HandleRemovedMembers;
-}
-
 impl orml_tokens2::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Balance = Balance;
@@ -304,12 +251,11 @@ impl orml_tokens2::Config for Test {
     type MaxReserves = ConstU32<0u32>;
     type DustRemovalWhitelist = CoreDustRemovalWhitelist;
     type ReserveIdentifier = [u8; 8];
-    type CurrencyHooks = INV4TokenHooks;
+    type CurrencyHooks = pallet_inv4::asset_hooks::INV4TokenHooks<Test>;
 }
 
 parameter_types! {
     pub const MaxMetadata: u32 = 10000;
-    pub const MaxCallers: u32 = 10000;
     pub const CoreSeedBalance: Balance = 1000000u128;
     pub const CoreCreationFee: Balance = UNIT;
     pub const StringLimit: u32 = 2125;
@@ -445,7 +391,6 @@ impl pallet_inv4::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type RuntimeCall = RuntimeCall;
-    type MaxCallers = MaxCallers;
     type CoreSeedBalance = CoreSeedBalance;
     type AssetsProvider = CoreAssets;
     type RuntimeOrigin = RuntimeOrigin;
@@ -460,6 +405,18 @@ impl pallet_inv4::Config for Test {
 
     type ParaId = ConstU32<2125>;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+    type InvitationPeriod = ConstU64<100>;
+    type MaxVestingSchedules = ConstU32<10>;
+    type MaxDependencies = ConstU32<5>;
+    type MaxBatchCalls = ConstU32<10>;
+    type MaxArchivedProposals = ConstU32<10>;
+    type RemoteVoteOrigin = pallet_xcm::EnsureXcm<Everything>;
+    type MaxHandleLength = ConstU32<32>;
+    type HandleDeposit = ConstU128<1_000_000_000_000>;
+    type MaxNameLength = ConstU32<20>;
+    type MaxDescriptionLength = ConstU32<300>;
+    type MaxImageUrlLength = ConstU32<60>;
+    type MaxWebsiteLength = ConstU32<60>;
 }
 
 parameter_types! {
@@ -2,7 +2,9 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_runtime::{Perbill, Percent};
+use sp_std::vec::Vec;
 
 /// Voting weight of an IPT
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
@@ -13,6 +15,27 @@ pub enum OneOrPercent {
     ZeroPoint(Percent),
 }
 
+/// Minimum amount of core members that must vote on a proposal for it to be executed
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    MaxEncodedLen,
+    Debug,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum MinimumVoters {
+    /// Absolute number of members
+    Absolute(u32),
+    /// Share of the core's current members
+    Proportion(Perbill),
+}
+
 /// Entity is parent or child?
 #[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
 pub enum Parentage<AccountId, IpsId> {
@@ -37,9 +60,34 @@ pub enum BoolOrWasm<Wasm> {
     Wasm(Wasm),
 }
 
+/// Core metadata, shared by the pallets that display information about cores
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Default,
+    Eq,
+    PartialEq,
+    MaxEncodedLen,
+    Debug,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct CoreMetadata<Name, Description, Image, Website, Hash> {
+    pub name: Name,
+    pub description: Description,
+    /// URL of the core's image
+    pub image: Image,
+    /// URL of the core's website
+    pub website: Website,
+    /// Hash of the core's off-chain documents, e.g. a charter or whitepaper
+    pub content_hash: Option<Hash>,
+}
+
 /// Core IP Set struct
 #[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, Debug, TypeInfo)]
-pub struct CoreInfo<AccountId, CoreMetadataOf> {
+pub struct CoreInfo<AccountId, CoreMetadataOf, Balance> {
     /// IPS parentage
     pub account: AccountId,
     /// IPS metadata
@@ -50,8 +98,32 @@ pub struct CoreInfo<AccountId, CoreMetadataOf> {
     /// Invariant: If set to `One`, 100% of tokens that have non-zero voting weight must approve
     pub minimum_support: Perbill,
     pub required_approval: Perbill,
+    /// Minimum amount of members that must vote on a proposal, checked alongside support and approval.
+    pub minimum_voters: Option<MinimumVoters>,
+
+    pub frozen_tokens: bool,
+    /// If set, the voting token can only be transferred to other members or whitelisted accounts.
+    pub members_only_transfers: bool,
 
+    /// Maximum total supply of the voting token, uncapped if `None`.
+    pub max_supply: Option<Balance>,
+    /// Share of the voting token supply that must approve a proposal changing `max_supply` or this threshold.
+    pub max_supply_supermajority: Perbill,
+}
+
+/// Core created at genesis, e.g. for dev chains and test networks
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GenesisCore<CoreMetadataOf, AccountId, Balance> {
+    pub metadata: CoreMetadataOf,
+    pub minimum_support: Perbill,
+    pub required_approval: Perbill,
+    pub minimum_voters: Option<MinimumVoters>,
     pub frozen_tokens: bool,
+    pub members_only_transfers: bool,
+    /// Maximum total supply of the voting token, must cover the balances of `members`.
+    pub max_supply: Option<Balance>,
+    /// Initial members and their voting token balances, at least one is required.
+    pub members: Vec<(AccountId, Balance)>,
 }
 
 /// IPF Info